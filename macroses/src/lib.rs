@@ -19,6 +19,9 @@ use crate::serialize::impl_serialize;
 /// }
 /// ```
 /// WARNING: It serializes as unsigned transaction.
+///
+/// With `#[binary(serializer_only)]` on the struct, only `TransactionSerializer` is implemented
+/// (for a binary layout serialized by a hand-written `Transaction`).
 #[proc_macro_derive(BinarySerialize, attributes(binary))]
 pub fn binary_serialize(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -142,9 +142,10 @@ pub(crate) fn impl_serialize(ast: &syn::DeriveInput) -> TokenStream {
         }
         panic!("No #[binary] attribute for field {:?}", field.ident);
     });
-    let fields_data = fields_data.flatten()
-        .sorted_by(|a, b| Ord::cmp(&(a.0, a.1), &(b.0, b.1)));
-    let body = fields_data.map(|field| {
+    let fields_data: Vec<_> = fields_data.flatten()
+        .sorted_by(|a, b| Ord::cmp(&(a.0, a.1), &(b.0, b.1)))
+        .collect();
+    let body = fields_data.iter().map(|field| {
         let (type_code, nth, field_name) = field;
         quote!(
             crate::serialize::XrplBinaryField {
@@ -158,16 +159,83 @@ pub(crate) fn impl_serialize(ast: &syn::DeriveInput) -> TokenStream {
     });
     let body = proc_macro2::TokenStream::from_iter(body);
 
+    // Multi-signed: `SigningPubKey` is present, but empty, `TxnSignature` is absent,
+    // and `Signers` is inserted in the canonical order.
+    let blob_type_code = DEFINITIONS.types["Blob"];
+    let signing_pub_key = (blob_type_code, DEFINITIONS.fields[&("SigningPubKey".to_owned(), "Blob".to_owned())].nth);
+    let txn_signature = (blob_type_code, DEFINITIONS.fields[&("TxnSignature".to_owned(), "Blob".to_owned())].nth);
+    let signers_type_code = DEFINITIONS.types["STArray"];
+    let signers_nth = DEFINITIONS.fields[&("Signers".to_owned(), "STArray".to_owned())].nth;
+    let signers = quote!(
+        if !signers.is_empty() {
+            crate::serialize::XrplBinaryField {
+                xrpl_type: &crate::serialize::XrplType {
+                    type_code: #signers_type_code,
+                },
+                field_code: #signers_nth,
+                value: &crate::txs::multisign::Signers(signers),
+            }.serialize(writer)?;
+        }
+    );
+    let mut multisigned_body = Vec::new();
+    let mut signers_written = false;
+    for (type_code, nth, field_name) in &fields_data {
+        if !signers_written && (*type_code, *nth) > (signers_type_code, signers_nth) {
+            multisigned_body.push(signers.clone());
+            signers_written = true;
+        }
+        if (*type_code, *nth) == txn_signature {
+            continue;
+        }
+        let value = if (*type_code, *nth) == signing_pub_key {
+            quote!(&::std::vec::Vec::<u8>::new())
+        } else {
+            quote!(&self.#field_name)
+        };
+        multisigned_body.push(quote!(
+            crate::serialize::XrplBinaryField {
+                xrpl_type: &crate::serialize::XrplType {
+                    type_code: #type_code,
+                },
+                field_code: #nth,
+                value: #value,
+            }.serialize(writer)?;
+        ));
+    }
+    if !signers_written {
+        multisigned_body.push(signers);
+    }
+    let multisigned_body = proc_macro2::TokenStream::from_iter(multisigned_body);
+
     let struct_name = &ast.ident;
-    quote!(
-        impl Transaction for #struct_name {
-            fn set_signature(&mut self, signature: Vec<u8>) {
-                self.signature = Some(signature);
-            }
-            fn set_public_key(&mut self, public_key: AccountPublicKey) {
-                self.public_key = Some(public_key);
-            }
+    // `#[binary(serializer_only)]`: a binary layout used by a hand-written `Transaction`, not a transaction itself.
+    let serializer_only = ast.attrs.iter().any(|attr| {
+        if let Meta::List(list) = &attr.meta {
+            list.path.is_ident("binary") && list.tokens.clone().into_iter().any(|t| if let TokenTree::Ident(id) = t {
+                id == "serializer_only"
+            } else {
+                false
+            })
+        } else {
+            false
         }
+    });
+    let transaction_impl = if serializer_only {
+        quote!()
+    } else {
+        quote!(
+            impl Transaction for #struct_name {
+                fn set_signature(&mut self, signature: Vec<u8>) {
+                    self.signature = Some(signature);
+                }
+                fn set_public_key(&mut self, public_key: AccountPublicKey) {
+                    self.public_key = Some(public_key);
+                }
+            }
+        )
+    };
+    quote!(
+        #transaction_impl
         impl TransactionSerializer for #struct_name {
             fn serialize(&self, prefix: &[u8; 4], writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
                 use crate::serialize::Serialize; // TODO: needed?
@@ -175,6 +243,17 @@ pub(crate) fn impl_serialize(ast: &syn::DeriveInput) -> TokenStream {
                 #body
                 Ok(())
             }
+            fn serialize_multisigned(
+                &self,
+                prefix: &[u8; 4],
+                signers: &[crate::txs::multisign::Signer],
+                writer: &mut dyn ::std::io::Write,
+            ) -> ::std::io::Result<()> {
+                use crate::serialize::Serialize;
+                writer.write_all(prefix)?;
+                #multisigned_body
+                Ok(())
+            }
         }
     ).into()
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+
+/// `Api` configured with admin access to the server
+///
+/// Admin methods take this instead of `Api`,
+/// so that they cannot be called by mistake through a public connection.
+pub struct AdminApi<'a, A: Api> {
+    api: &'a A,
+}
+
+impl<'a, A: Api> AdminApi<'a, A>
+    where A::Error: From<XrplError>
+{
+    /// Check by `ping` that the connection has the admin role; `XrplError::NotAdmin` if not.
+    pub async fn new(api: &'a A) -> Result<AdminApi<'a, A>, A::Error> {
+        let request = Request {
+            command: "ping",
+            api_version: Some(1),
+            params: Value::Object(serde_json::Map::new()),
+        };
+        let response = api.call(request).await?;
+        if response.result.get("role").and_then(Value::as_str) == Some("admin") {
+            Ok(Self { api })
+        } else {
+            Err(XrplError::NotAdmin.into())
+        }
+    }
+    /// Trust that the connection is admin without checking.
+    pub fn new_unchecked(api: &'a A) -> Self {
+        Self { api }
+    }
+    pub fn api(&self) -> &'a A {
+        self.api
+    }
+}
@@ -0,0 +1,34 @@
+use crate::connection::{Api, XrplError};
+use crate::methods::server_info::server_info;
+
+/// `Api` known to be connected to a Clio server
+///
+/// Clio-only methods take this instead of `Api`,
+/// so that they are not sent to rippled (that would answer `unknownCmd`).
+pub struct ClioApi<'a, A: Api> {
+    api: &'a A,
+}
+
+impl<'a, A: Api> ClioApi<'a, A>
+    where A::Error: From<XrplError>
+{
+    /// Check by `server_info` that the server is Clio; `XrplError::NotClio` if not.
+    ///
+    /// Clio forwards some requests to rippled and marks such responses by `forwarded`,
+    /// its own `server_info` has `clio_version`.
+    pub async fn new(api: &'a A) -> Result<ClioApi<'a, A>, A::Error> {
+        let response = server_info(api).await?;
+        if response.forwarded || response.result.info.clio_version.is_some() {
+            Ok(Self { api })
+        } else {
+            Err(XrplError::NotClio.into())
+        }
+    }
+    /// Trust that the server is Clio without checking.
+    pub fn new_unchecked(api: &'a A) -> Self {
+        Self { api }
+    }
+    pub fn api(&self) -> &'a A {
+        self.api
+    }
+}
@@ -1,5 +1,5 @@
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Display;
 use fragile::Fragile;
 use serde_json::Value;
@@ -18,12 +18,17 @@ use crate::response::{Response, StreamedResponse};
 #[display("Server error code: {}", self.code)]
 pub struct XrplStatusError {
     pub code: String,
+    /// The whole error response, for errors carrying additional fields (such as `searched_all`).
+    pub result: Option<Value>,
 }
 
 impl XrplStatusError {
     #[allow(clippy::new_without_default)]
     pub fn new(code: String) -> Self {
-        Self { code }
+        Self { code, result: None }
+    }
+    pub fn with_result(code: String, result: Value) -> Self {
+        Self { code, result: Some(result) }
     }
 }
 
@@ -71,6 +76,10 @@ pub enum XrplError {
     XrplStatus(XrplStatusError),
     #[display("Cannot construct JSON object (internal error)")]
     CannotConstructJson,
+    #[display("Server is not Clio, but the command is Clio-only")]
+    NotClio,
+    #[display("Connection is not admin, but the command is admin-only")]
+    NotAdmin,
 }
 
 impl de::Error for XrplError {
@@ -113,20 +122,83 @@ impl Api for JsonRpcApi {
 pub struct WebSocketApi {
     client: WebSocket,
     responses: Fragile<RefCell<HashMap<u64, Response>>>,
+    /// Asynchronous (not `response`) messages, such as `path_find` updates, not yet taken by `next_event`;
+    /// no more than `MAX_QUEUED_EVENTS`, the oldest are dropped.
+    events: Fragile<RefCell<VecDeque<Value>>>,
     id: Fragile<Cell<u64>>,
 }
 
+/// Limit of asynchronous messages kept for `next_event`, so that subscription streams
+/// do not take unbounded memory when only `call` is used.
+pub const MAX_QUEUED_EVENTS: usize = 1024;
+
 impl WebSocketApi {
     pub fn new(client: WebSocket) -> Self {
         Self {
             client,
             responses: Fragile::new(RefCell::new(HashMap::new())),
+            events: Fragile::new(RefCell::new(VecDeque::new())),
             id: Fragile::new(Cell::new(0)),
         }
     }
     pub async fn reconnect(&self) -> Result<(), XrplError> {
         self.client.reconnect().await.map_err(|e| XrplError::Connection(e.to_string()))
     }
+    /// Wait for the next asynchronous (not `response`) message.
+    ///
+    /// Responses to requests received meanwhile are kept for their waiters.
+    pub async fn next_event(&self) -> Result<Value, XrplError> {
+        self.next_event_where(|_| true).await
+    }
+    /// Wait for the next asynchronous message of type `r#type` (such as `"path_find"`).
+    ///
+    /// Asynchronous messages of other types received meanwhile are kept for `next_event`.
+    pub async fn next_event_of_type(&self, r#type: &str) -> Result<Value, XrplError> {
+        self.next_event_where(|event| event.get("type").and_then(Value::as_str) == Some(r#type)).await
+    }
+    fn queue_event(&self, event: Value) {
+        let mut events = self.events.get().borrow_mut();
+        if events.len() == MAX_QUEUED_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+    /// Forget the state lost on disconnect.
+    fn clear(&self) {
+        self.responses.get().borrow_mut().clear();
+        self.events.get().borrow_mut().clear();
+    }
+    async fn next_event_where(&self, matches: impl Fn(&Value) -> bool) -> Result<Value, XrplError> {
+        loop {
+            {
+                let mut events = self.events.get().borrow_mut();
+                if let Some(pos) = events.iter().position(&matches) {
+                    return Ok(events.remove(pos).unwrap());
+                }
+            }
+            match self.client.recv().await.map_err(|e| Connection(e.to_string()))? {
+                Message::Open => {},
+                Message::Close => {
+                    self.client.disconnect().await.map_err(|e| Connection(e.to_string()))?;
+                    self.clear();
+                    return Err(XrplError::Disconnect);
+                },
+                Message::Text(msg) => {
+                    let r: Value = serde_json::from_str(&msg)?;
+                    if r.get("type") != Some(&Value::String(RESPONSE_KEY.clone())) {
+                        if matches(&r) {
+                            return Ok(r);
+                        }
+                        self.queue_event(r);
+                        continue;
+                    }
+                    let response = StreamedResponse::from_json(&r)?;
+                    self.responses.get().borrow_mut().insert(response.id, response.result);
+                },
+                Message::Binary(_) => {},
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -172,27 +244,31 @@ impl<'a> WebSocketMessageWaiterWithoutDrop<'a> {
     }
     pub async fn wait(&self) -> Result<Response, XrplError> {
         loop {
+            // May have been received by another waiter or by `next_event`.
+            if let Some(response) = self.api.responses.get().borrow_mut().remove(&self.id) {
+                return Ok(response);
+            }
             match self.api.client.recv().await.map_err(|e| Connection(e.to_string()))? {
                 Message::Open => {},
                 Message::Close => {
                     self.api.client.disconnect().await.map_err(|e| Connection(e.to_string()))?; // Prevent attempts to re-connect...
                     // ... because we lost state.
-                    self.api.responses.get().borrow_mut().clear();
+                    self.api.clear();
                     return Err(XrplError::Disconnect);
                 },
                 Message::Text(msg) => {
                     let r: Value = serde_json::from_str(&msg)?;
                     if r.get("type") != Some(&Value::String(RESPONSE_KEY.clone())) {
-                        // TODO: Deal with non-`response` ("asynchronous") WebSocket messages.
+                        self.api.queue_event(r);
                         continue;
                     }
                     let response: Result<StreamedResponse, XrplError> = StreamedResponse::from_json(&r);
                     match response {
                         Ok(response) => {
-                            self.api.responses.get().borrow_mut().insert(response.id, response.result);
-                            if let Some(response) = self.api.responses.get().borrow_mut().remove(&response.id) {
-                                return Ok(response);
+                            if response.id == self.id {
+                                return Ok(response.result);
                             }
+                            self.api.responses.get().borrow_mut().insert(response.id, response.result);
                         },
                         Err(err) => {
                             return Err(err);
@@ -0,0 +1,95 @@
+//! PREIMAGE-SHA-256 crypto-conditions (draft-thomas-crypto-conditions), as used by escrows
+//!
+//! The fulfillment is secret until the escrow is finished, so the preimage should be random,
+//! such as 32 bytes from `methods::random`.
+
+use sha2::{Digest, Sha256};
+
+const TYPE_PREIMAGE_SHA256: u8 = 0;
+
+/// Condition and fulfillment of the PREIMAGE-SHA-256 type
+#[derive(Clone, Debug)]
+pub struct PreimageSha256 {
+    pub preimage: Vec<u8>,
+}
+
+fn der_length(buf: &mut Vec<u8>, length: usize) {
+    if length < 0x80 {
+        buf.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let bytes = &bytes[bytes.iter().position(|&b| b != 0).unwrap()..];
+        buf.push(0x80 | bytes.len() as u8);
+        buf.extend_from_slice(bytes);
+    }
+}
+
+fn der_tagged(buf: &mut Vec<u8>, tag: u8, content: &[u8]) {
+    buf.push(tag);
+    der_length(buf, content.len());
+    buf.extend_from_slice(content);
+}
+
+/// Unsigned integer with the minimal number of bytes
+fn der_uint(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+    let mut result = Vec::new();
+    if bytes[start] & 0x80 != 0 {
+        result.push(0);
+    }
+    result.extend_from_slice(&bytes[start..]);
+    result
+}
+
+impl PreimageSha256 {
+    pub fn new(preimage: Vec<u8>) -> Self {
+        Self { preimage }
+    }
+    pub fn fingerprint(&self) -> [u8; 32] {
+        Sha256::digest(&self.preimage).into()
+    }
+    /// Cost of the condition: the length of the preimage
+    pub fn cost(&self) -> u64 {
+        self.preimage.len() as u64
+    }
+    /// DER-encoded condition, for `Condition` of `EscrowCreate` and `EscrowFinish`
+    pub fn condition(&self) -> Vec<u8> {
+        let mut content = Vec::new();
+        der_tagged(&mut content, 0x80, &self.fingerprint());
+        der_tagged(&mut content, 0x81, &der_uint(self.cost()));
+        let mut result = Vec::new();
+        der_tagged(&mut result, 0xA0 | TYPE_PREIMAGE_SHA256, &content);
+        result
+    }
+    /// DER-encoded fulfillment, for `Fulfillment` of `EscrowFinish`
+    pub fn fulfillment(&self) -> Vec<u8> {
+        let mut content = Vec::new();
+        der_tagged(&mut content, 0x80, &self.preimage);
+        let mut result = Vec::new();
+        der_tagged(&mut result, 0xA0 | TYPE_PREIMAGE_SHA256, &content);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_condition::PreimageSha256;
+
+    #[test]
+    fn test_empty_preimage() {
+        let condition = PreimageSha256::new(Vec::new());
+        assert_eq!(hex::encode_upper(condition.fulfillment()), "A0028000");
+        assert_eq!(
+            hex::encode_upper(condition.condition()),
+            "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+        );
+    }
+
+    #[test]
+    fn test_long_preimage() {
+        let condition = PreimageSha256::new(vec![0; 200]);
+        assert_eq!(&condition.fulfillment()[..6], &[0xA0, 0x81, 0xCB, 0x80, 0x81, 0xC8]);
+        assert_eq!(&condition.condition()[36..], &[0x81, 0x02, 0x00, 0xC8]);
+    }
+}
@@ -8,6 +8,11 @@ pub mod serialize;
 pub mod objects;
 pub mod methods;
 pub mod txs;
+pub mod clio;
+pub mod admin;
+pub mod raw;
+pub mod crypto_condition;
+pub mod ticket_pool;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,66 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct AccountNftsRequest {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountNftsPaginator {
+    #[serde(rename = "Flags")]
+    pub flags: u32,
+    #[serde(rename = "Issuer")]
+    pub issuer: Address,
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Hash<32>,
+    #[serde(rename = "NFTokenTaxon")]
+    pub nftoken_taxon: u32,
+    #[serde(rename = "URI")]
+    #[serde(default, with = "crate::types::option_blob")]
+    pub uri: Option<Vec<u8>>,
+    pub nft_serial: u32,
+    /// In units of 1/100000, so `50000` means 50%.
+    #[serde(rename = "TransferFee")]
+    pub transfer_fee: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountNftsResponse {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for AccountNftsPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("account_nfts").ok_or::<XrplError>(de::Error::missing_field("account_nfts"))
+    }
+}
+
+pub async fn account_nfts<'a, A>(
+    api: &'a A,
+    data: &'a AccountNftsRequest,
+) -> Result<(TypedResponse<AccountNftsResponse>, Paginator<'a, A, AccountNftsPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "account_nfts",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
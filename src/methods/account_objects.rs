@@ -0,0 +1,112 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::ledger_entry::{LedgerEntry, LedgerEntryType};
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct AccountObjectsRequest {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    /// If set, return only objects of this type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<LedgerEntryType>,
+    /// If `true`, return only objects that would block deletion of the account.
+    pub deletion_blockers_only: Option<bool>,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountObjectsResponse {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for LedgerEntry {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("account_objects").ok_or::<XrplError>(de::Error::missing_field("account_objects"))
+    }
+}
+
+pub async fn account_objects<'a, A>(
+    api: &'a A,
+    data: &'a AccountObjectsRequest,
+) -> Result<(TypedResponse<AccountObjectsResponse>, Paginator<'a, A, LedgerEntry>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "account_objects",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+    use crate::objects::ledger_entry::LedgerEntry;
+    use crate::paginate::PaginatorExtractor;
+
+    #[test]
+    fn test_deserialize_account_objects() {
+        let result = json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "account_objects": [
+                {
+                    "Flags": 0,
+                    "LedgerEntryType": "SignerList",
+                    "OwnerNode": "0000000000000000",
+                    "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+                    "PreviousTxnLgrSeq": 16061435,
+                    "SignerEntries": [
+                        {
+                            "SignerEntry": {
+                                "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                                "SignerWeight": 2
+                            }
+                        },
+                        {
+                            "SignerEntry": {
+                                "Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n",
+                                "SignerWeight": 1
+                            }
+                        },
+                        {
+                            "SignerEntry": {
+                                "Account": "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v",
+                                "SignerWeight": 1
+                            }
+                        }
+                    ],
+                    "SignerListID": 0,
+                    "SignerQuorum": 3,
+                    "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+                }
+            ],
+            "ledger_current_index": 16061436,
+            "validated": false
+        });
+        let objects = LedgerEntry::list(&result).unwrap();
+        let signer_list = match LedgerEntry::deserialize(&objects[0]).unwrap() {
+            LedgerEntry::SignerList(signer_list) => signer_list,
+            entry => panic!("unexpected {:?}", entry),
+        };
+        assert_eq!(signer_list.owner_node, 0);
+        assert_eq!(signer_list.signer_quorum, 3);
+        assert_eq!(signer_list.signer_entries.len(), 3);
+        assert_eq!(signer_list.signer_entries[0].signer_weight, 2);
+        assert!(signer_list.signer_entries.iter().all(|entry| entry.wallet_locator.is_none()));
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::AnyAmount;
+use crate::objects::offer::OfferFlags;
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct AccountOffersRequest {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountOffersPaginator {
+    pub flags: OfferFlags,
+    /// Sequence number of the transaction that created this offer
+    pub seq: u32,
+    pub taker_gets: AnyAmount,
+    pub taker_pays: AnyAmount,
+    /// Exchange rate of the offer, as the ratio of `taker_pays` to `taker_gets`
+    #[serde(with = "crate::types::token")]
+    pub quality: f64,
+    pub expiration: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountOffersResponse {
+    pub account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for AccountOffersPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("offers").ok_or::<XrplError>(de::Error::missing_field("offers"))
+    }
+}
+
+pub async fn account_offers<'a, A>(
+    api: &'a A,
+    data: &'a AccountOffersRequest,
+) -> Result<(TypedResponse<AccountOffersResponse>, Paginator<'a, A, AccountOffersPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "account_offers",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
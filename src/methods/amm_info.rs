@@ -0,0 +1,91 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::{Amount, AnyAmount, Issue};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+/// How to find the AMM
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum AmmSelector {
+    Assets {
+        asset: Issue,
+        asset2: Issue,
+    },
+    Account {
+        amm_account: Address,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct AmmInfoRequest {
+    #[serde(flatten)]
+    pub amm: AmmSelector,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmAuthAccount {
+    pub account: Address,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmAuctionSlot {
+    pub account: Address,
+    #[serde(default)]
+    pub auth_accounts: Vec<AmmAuthAccount>,
+    pub discounted_fee: u16,
+    /// ISO 8601 time
+    pub expiration: String,
+    pub price: Amount,
+    /// Current 72-minute interval (0 to 19) of the 24-hour slot
+    pub time_interval: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmVoteSlot {
+    pub account: Address,
+    pub trading_fee: u16,
+    pub vote_weight: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmDescription {
+    pub account: Address,
+    /// Pool balance of the first asset
+    pub amount: AnyAmount,
+    pub amount2: AnyAmount,
+    pub asset_frozen: Option<bool>,
+    pub asset2_frozen: Option<bool>,
+    pub auction_slot: Option<AmmAuctionSlot>,
+    /// Total outstanding LP tokens
+    pub lp_token: Amount,
+    /// In units of 1/100000
+    pub trading_fee: u16,
+    #[serde(default)]
+    pub vote_slots: Vec<AmmVoteSlot>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmInfoResponse {
+    pub amm: AmmDescription,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+pub async fn amm_info<'a, A>(api: &'a A, data: &'a AmmInfoRequest)
+    -> Result<TypedResponse<AmmInfoResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "amm_info",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
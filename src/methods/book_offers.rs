@@ -0,0 +1,56 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::{AnyAmount, Issue};
+use crate::objects::offer::Offer;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct BookOffersRequest {
+    pub taker_gets: Issue,
+    pub taker_pays: Issue,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    /// Address to compute funding from the perspective of
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<Address>,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BookOffer {
+    #[serde(flatten)]
+    pub offer: Offer,
+    /// Amount of the `taker_gets` currency the owner has (in drops for XRP).
+    /// Present only for the first offer of each owner.
+    #[serde(default, with = "crate::types::option_token")]
+    pub owner_funds: Option<f64>,
+    #[serde(with = "crate::types::token")]
+    pub quality: f64,
+    /// Present if the offer is partially funded.
+    pub taker_gets_funded: Option<AnyAmount>,
+    pub taker_pays_funded: Option<AnyAmount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BookOffersResponse {
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub offers: Vec<BookOffer>,
+    pub validated: Option<bool>,
+}
+
+pub async fn book_offers<'a, A>(api: &'a A, data: &'a BookOffersRequest)
+    -> Result<TypedResponse<BookOffersResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "book_offers",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
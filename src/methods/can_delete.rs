@@ -0,0 +1,61 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use serde_json::json;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+/// Which ledgers online deletion may delete (with `advisory_delete` enabled)
+#[derive(Clone, Debug)]
+pub enum CanDelete {
+    /// Only query the current setting.
+    Query,
+    Never,
+    Always,
+    /// Up to the current validated ledger
+    Now,
+    /// Up to the ledger with this index
+    Index(u32),
+    /// Up to the ledger with this hash
+    Hash(Hash<32>),
+}
+
+impl Serialize for CanDelete {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Serialize)]
+        struct CanDelete2 {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            can_delete: Option<serde_json::Value>,
+        }
+        let can_delete = match self {
+            CanDelete::Query => None,
+            CanDelete::Never => Some(json!("never")),
+            CanDelete::Always => Some(json!("always")),
+            CanDelete::Now => Some(json!("now")),
+            CanDelete::Index(index) => Some(json!(index)),
+            CanDelete::Hash(hash) => Some(json!(hash)),
+        };
+        CanDelete2 { can_delete }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CanDeleteResponse {
+    /// Maximum ledger index that may be deleted; 0 is never, `u32::MAX` is always.
+    pub can_delete: u32,
+}
+
+/// Query or change which ledgers online deletion may delete (admin only).
+pub async fn can_delete<'a, A>(api: &AdminApi<'a, A>, data: &'a CanDelete)
+    -> Result<TypedResponse<CanDeleteResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "can_delete",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.api().call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -0,0 +1,76 @@
+use byteorder::{BigEndian, WriteBytesExt};
+use serde::{de, Deserialize, Serialize};
+use xrpl::core::keypairs::sign;
+use crate::hashes::{AccountPublicKey, SecretKey};
+use crate::connection::{Api, XrplError};
+use crate::methods::sign::{KeyType, SigningSecret};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+/// Prefix of payment channel claim messages (`CLM\0`)
+pub const HASH_PREFIX_PAYMENT_CHANNEL_CLAIM: [u8; 4] = [0x43, 0x4C, 0x4D, 0x00];
+
+#[derive(Debug, Serialize)]
+pub struct ChannelAuthorizeRequest {
+    pub channel_id: Hash<32>,
+    /// Cumulative amount of XRP (in drops) the claim authorizes
+    #[serde(with = "crate::types::xrp")]
+    pub amount: u64,
+    #[serde(flatten)]
+    pub secret: SigningSecret,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<KeyType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChannelAuthorizeResponse {
+    #[serde(with = "crate::types::blob")]
+    pub signature: Vec<u8>,
+}
+
+/// Sign a payment channel claim by the server.
+///
+/// This sends the secret to the server, prefer `authorize_claim` that signs locally.
+pub async fn channel_authorize<'a, A>(api: &'a A, data: &'a ChannelAuthorizeRequest)
+    -> Result<TypedResponse<ChannelAuthorizeResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "channel_authorize",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+/// The message signed by a payment channel claim
+pub fn claim_message(channel_id: &Hash<32>, amount: u64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(4 + 32 + 8);
+    message.extend_from_slice(&HASH_PREFIX_PAYMENT_CHANNEL_CLAIM);
+    message.extend_from_slice(&channel_id.0);
+    message.write_u64::<BigEndian>(amount).unwrap(); // writing to `Vec` does not fail
+    message
+}
+
+/// Private key in the format of `xrpl-rust` (prefixed by key type)
+pub(crate) fn prefixed_private_key(public_key: &AccountPublicKey, secret_key: &SecretKey) -> String {
+    // Ed25519 public keys start with `0xED`, secp256k1 ones with `0x02` or `0x03`.
+    let prefix = if public_key.0[0] == 0xED { "ED" } else { "00" };
+    prefix.to_owned() + &hex::encode_upper(secret_key.0.0)
+}
+
+/// Sign a payment channel claim locally (the same as `channel_authorize`, but without a server).
+///
+/// `public_key` is needed to determine the key type.
+pub fn authorize_claim(channel_id: &Hash<32>,
+                       amount: u64,
+                       public_key: &AccountPublicKey,
+                       secret_key: &SecretKey)
+                       -> Result<Vec<u8>, XrplError>
+{
+    let signature = sign(&claim_message(channel_id, amount), &prefixed_private_key(public_key, secret_key))
+        .map_err(|e| XrplError::Message(e.to_string()))?;
+    hex::decode(signature).map_err(|e| XrplError::Message(e.to_string()))
+}
@@ -0,0 +1,87 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use xrpl::core::keypairs::is_valid_message;
+use crate::hashes::AccountPublicKey;
+use crate::connection::{Api, XrplError};
+use crate::methods::channel_authorize::claim_message;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug)]
+pub struct ChannelVerifyRequest {
+    pub channel_id: Hash<32>,
+    /// In drops
+    pub amount: u64,
+    pub public_key: AccountPublicKey,
+    pub signature: Vec<u8>,
+}
+
+impl Serialize for ChannelVerifyRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct ChannelVerifyRequest2<'a> {
+            pub channel_id: &'a Hash<32>,
+            #[serde(with = "crate::types::xrp")]
+            pub amount: u64,
+            pub public_key: String,
+            pub signature: String,
+        }
+        ChannelVerifyRequest2 {
+            channel_id: &self.channel_id,
+            amount: self.amount,
+            public_key: self.public_key.encode_hex(),
+            signature: hex::encode_upper(&self.signature),
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChannelVerifyResponse {
+    pub signature_verified: bool,
+}
+
+/// Verify a payment channel claim by the server (see also `verify_claim`).
+pub async fn channel_verify<'a, A>(api: &'a A, data: &'a ChannelVerifyRequest)
+    -> Result<TypedResponse<ChannelVerifyResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "channel_verify",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+/// Verify a payment channel claim locally (the same as `channel_verify`, but without a server).
+pub fn verify_claim(channel_id: &Hash<32>, amount: u64, public_key: &AccountPublicKey, signature: &[u8]) -> bool {
+    is_valid_message(
+        &claim_message(channel_id, amount),
+        &hex::encode_upper(signature),
+        &public_key.encode_hex(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use xrpl::core::keypairs::derive_keypair;
+    use crate::hashes::{Encoding, SecretKey};
+    use crate::methods::channel_authorize::authorize_claim;
+    use crate::methods::channel_verify::verify_claim;
+    use crate::types::Hash;
+
+    #[test]
+    fn test_claim_round_trip() {
+        let (public_key, private_key) = derive_keypair("sEdTWjtgXkxfh2p4KrTyDzmKu8aYNnK", false).unwrap();
+        let (public_key, private_key) =
+            (hex::decode(public_key).unwrap(), hex::decode(private_key).unwrap());
+        let public_key = Encoding(public_key.as_slice().try_into().unwrap());
+        let private_key = SecretKey(Hash(<[u8; 32]>::try_from(&private_key[1..]).unwrap()));
+        let channel_id =
+            Hash::from_hex("5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3").unwrap();
+        let signature = authorize_claim(&channel_id, 1000000, &public_key, &private_key).unwrap();
+        assert!(verify_claim(&channel_id, 1000000, &public_key, &signature));
+        assert!(!verify_claim(&channel_id, 1000001, &public_key, &signature));
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{de, Deserialize, Serialize};
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Serialize)]
+pub struct ConnectRequest {
+    /// IP address of the peer
+    pub ip: String,
+    /// Default is the peer protocol port from the server's config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConnectResponse {
+    pub message: String,
+}
+
+/// Make the server connect to a specific peer (admin only).
+pub async fn connect<'a, A>(api: &AdminApi<'a, A>, data: &'a ConnectRequest)
+    -> Result<TypedResponse<ConnectResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "connect",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.api().call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
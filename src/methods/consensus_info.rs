@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+/// The contents vary with the consensus state, only the common fields are typed.
+#[derive(Debug, Deserialize)]
+pub struct ConsensusInfo {
+    /// Such as `"open"`, `"establish"` or `"accepted"`
+    pub phase: Option<String>,
+    /// Such as `"no"` or `"yes"`
+    pub consensus: Option<String>,
+    pub proposing: Option<bool>,
+    pub validating: Option<bool>,
+    pub synched: Option<bool>,
+    pub ledger_seq: Option<u32>,
+    pub previous_proposers: Option<u32>,
+    pub previous_mseconds: Option<u32>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConsensusInfoResponse {
+    pub info: ConsensusInfo,
+}
+
+/// State of the consensus process, for debugging (admin only)
+pub async fn consensus_info<A>(api: &AdminApi<'_, A>)
+    -> Result<TypedResponse<ConsensusInfoResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "consensus_info",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
@@ -0,0 +1,38 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct DepositAuthorizedRequest {
+    pub source_account: Address,
+    pub destination_account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DepositAuthorizedResponse {
+    /// Whether `source_account` may send payments directly to `destination_account`
+    pub deposit_authorized: bool,
+    pub source_account: Address,
+    pub destination_account: Address,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+pub async fn deposit_authorized<'a, A>(api: &'a A, data: &'a DepositAuthorizedRequest)
+    -> Result<TypedResponse<DepositAuthorizedResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "deposit_authorized",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
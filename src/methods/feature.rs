@@ -0,0 +1,90 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug, Serialize)]
+pub struct FeatureRequest {
+    /// Amendment ID or name; all amendments if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Amendment {
+    pub id: Hash<32>,
+    pub name: Option<String>,
+    pub enabled: bool,
+    /// Whether this server knows how to apply the amendment
+    pub supported: bool,
+    /// Whether this server votes against the amendment
+    pub vetoed: bool,
+    /// Obsolete amendments are not voted on.
+    pub obsolete: bool,
+    /// The following are present only for amendments not yet enabled.
+    pub count: Option<u32>,
+    pub threshold: Option<u32>,
+    pub validations: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FeatureResponse {
+    pub features: Vec<Amendment>,
+}
+
+impl<'de> Deserialize<'de> for FeatureResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Debug, Deserialize)]
+        struct Amendment2 {
+            pub name: Option<String>,
+            pub enabled: bool,
+            pub supported: bool,
+            pub vetoed: Option<Value>,
+            pub count: Option<u32>,
+            pub threshold: Option<u32>,
+            pub validations: Option<u32>,
+        }
+        let mut value = Map::<String, Value>::deserialize(deserializer)?;
+        // All amendments are under `features`, a single one is at the top level.
+        let features = match value.remove("features") {
+            Some(Value::Object(features)) => features,
+            _ => value,
+        };
+        let features = features.into_iter()
+            .filter(|(_, amendment)| amendment.is_object())
+            .map(|(id, amendment)| {
+                let amendment = Amendment2::deserialize(amendment).map_err(de::Error::custom)?;
+                Ok(Amendment {
+                    id: Hash::from_hex(&id).map_err(de::Error::custom)?,
+                    name: amendment.name,
+                    enabled: amendment.enabled,
+                    supported: amendment.supported,
+                    vetoed: amendment.vetoed == Some(Value::Bool(true)),
+                    obsolete: amendment.vetoed == Some(Value::String("Obsolete".to_owned())),
+                    count: amendment.count,
+                    threshold: amendment.threshold,
+                    validations: amendment.validations,
+                })
+            })
+            .collect::<Result<_, D::Error>>()?;
+        Ok(FeatureResponse {
+            features,
+        })
+    }
+}
+
+/// Amendments known to the server and their status
+pub async fn feature<'a, A>(api: &'a A, data: &'a FeatureRequest)
+    -> Result<TypedResponse<FeatureResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "feature",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::u64_or_string;
+
+/// Transaction costs in drops
+#[derive(Debug, Deserialize)]
+pub struct FeeDrops {
+    #[serde(deserialize_with = "u64_or_string")]
+    pub base_fee: u64,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub median_fee: u64,
+    /// Minimum to be queued
+    #[serde(deserialize_with = "u64_or_string")]
+    pub minimum_fee: u64,
+    /// Minimum to be included into the open ledger immediately
+    #[serde(deserialize_with = "u64_or_string")]
+    pub open_ledger_fee: u64,
+}
+
+/// Transaction costs in fee levels (relative to `reference_level`)
+#[derive(Debug, Deserialize)]
+pub struct FeeLevels {
+    #[serde(deserialize_with = "u64_or_string")]
+    pub median_level: u64,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub minimum_level: u64,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub open_ledger_level: u64,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub reference_level: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeeResponse {
+    /// Number of transactions in the open ledger
+    #[serde(deserialize_with = "u64_or_string")]
+    pub current_ledger_size: u64,
+    /// Number of transactions in the queue
+    #[serde(deserialize_with = "u64_or_string")]
+    pub current_queue_size: u64,
+    pub drops: FeeDrops,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub expected_ledger_size: u64,
+    pub ledger_current_index: u32,
+    pub levels: FeeLevels,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub max_queue_size: u64,
+}
+
+pub async fn fee<A>(api: &A) -> Result<TypedResponse<FeeResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "fee",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
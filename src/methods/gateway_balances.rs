@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{decode_token_amount, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug)]
+pub struct GatewayBalancesRequest {
+    /// Issuing account
+    pub account: Address,
+    pub ledger: LedgerForRequest,
+    /// Operational addresses to exclude from the obligations
+    pub hotwallet: Vec<Address>,
+    /// Accept only an address (not a public key or a seed) as `account`.
+    pub strict: bool,
+}
+
+impl Serialize for GatewayBalancesRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct GatewayBalancesRequest2<'a> {
+            pub account: &'a Address,
+            #[serde(flatten)]
+            pub ledger: &'a LedgerForRequest,
+            pub hotwallet: Option<&'a Vec<Address>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub strict: Option<bool>,
+        }
+        GatewayBalancesRequest2 {
+            account: &self.account,
+            ledger: &self.ledger,
+            hotwallet: if self.hotwallet.is_empty() { None } else { Some(&self.hotwallet) },
+            strict: if self.strict { Some(true) } else { None },
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CurrencyBalance {
+    pub currency: String,
+    #[serde(with = "crate::types::token")]
+    pub value: f64,
+}
+
+#[derive(Debug)]
+pub struct GatewayBalancesResponse {
+    pub account: Address,
+    /// Total amounts issued to addresses not excluded, by currency
+    pub obligations: Vec<(String, f64)>,
+    /// Amounts held by the `hotwallet` addresses
+    pub balances: Vec<(Address, Vec<CurrencyBalance>)>,
+    /// Amounts issued to this account by other accounts
+    pub assets: Vec<(Address, Vec<CurrencyBalance>)>,
+    /// Frozen amounts issued to addresses not excluded
+    pub frozen_balances: Vec<(Address, Vec<CurrencyBalance>)>,
+    pub ledger: LedgerForResponse,
+    pub validated: bool,
+}
+
+fn decode_balances<E: de::Error>(balances: Option<HashMap<String, Vec<CurrencyBalance>>>)
+    -> Result<Vec<(Address, Vec<CurrencyBalance>)>, E>
+{
+    balances.unwrap_or_default().into_iter()
+        .map(|(address, list)| Ok((Address::decode(&address).map_err(de::Error::custom)?, list)))
+        .collect()
+}
+
+impl<'de> Deserialize<'de> for GatewayBalancesResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Debug, Deserialize)]
+        struct GatewayBalancesResponse2 {
+            pub account: Address,
+            pub obligations: Option<HashMap<String, String>>,
+            pub balances: Option<HashMap<String, Vec<CurrencyBalance>>>,
+            pub assets: Option<HashMap<String, Vec<CurrencyBalance>>>,
+            pub frozen_balances: Option<HashMap<String, Vec<CurrencyBalance>>>,
+            #[serde(flatten)]
+            pub ledger: LedgerForResponse,
+            pub validated: Option<bool>,
+        }
+        let value: GatewayBalancesResponse2 = GatewayBalancesResponse2::deserialize(deserializer)?;
+        Ok(GatewayBalancesResponse {
+            account: value.account,
+            obligations: value.obligations.unwrap_or_default().into_iter()
+                .map(|(currency, amount)| Ok((currency, decode_token_amount(&amount).map_err(de::Error::custom)?)))
+                .collect::<Result<_, D::Error>>()?,
+            balances: decode_balances(value.balances)?,
+            assets: decode_balances(value.assets)?,
+            frozen_balances: decode_balances(value.frozen_balances)?,
+            ledger: value.ledger,
+            validated: value.validated == Some(true),
+        })
+    }
+}
+
+pub async fn gateway_balances<'a, A>(api: &'a A, data: &'a GatewayBalancesRequest)
+    -> Result<TypedResponse<GatewayBalancesResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "gateway_balances",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
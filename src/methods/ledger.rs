@@ -0,0 +1,201 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::clio::ClioApi;
+use crate::connection::{Api, XrplError};
+use crate::objects::ledger_entry::LedgerEntry;
+use crate::request::{Request, TypedRequest};
+use crate::response::TypedResponse;
+use crate::types::{u64_or_string, Hash, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug)]
+pub struct LedgerRequest {
+    pub ledger: LedgerForRequest,
+    /// Return the transactions of the ledger (as hashes unless `expand`).
+    pub transactions: bool,
+    /// Return full transactions instead of their hashes.
+    pub expand: bool,
+    /// Include `owner_funds` in `OfferCreate` transactions (needs `expand`).
+    pub owner_funds: bool,
+    /// Return transactions as binary blobs (needs `expand`).
+    pub binary: bool,
+    /// Return the transaction queue (only for the current ledger).
+    pub queue: bool,
+}
+
+impl Serialize for LedgerRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct LedgerRequest2<'a> {
+            #[serde(flatten)]
+            pub ledger: &'a LedgerForRequest,
+            pub transactions: Option<bool>,
+            pub expand: Option<bool>,
+            pub owner_funds: Option<bool>,
+            pub binary: Option<bool>,
+            pub queue: Option<bool>,
+        }
+        let flag = |value: bool| if value { Some(true) } else { None };
+        LedgerRequest2 {
+            ledger: &self.ledger,
+            transactions: flag(self.transactions),
+            expand: flag(self.expand),
+            owner_funds: flag(self.owner_funds),
+            binary: flag(self.binary),
+            queue: flag(self.queue),
+        }.serialize(serializer)
+    }
+}
+
+/// Transaction in binary format, as returned with `binary` option
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinaryTransaction {
+    #[serde(with = "crate::types::blob")]
+    pub tx_blob: Vec<u8>,
+    #[serde(with = "crate::types::blob")]
+    pub meta: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum LedgerTransactions {
+    Hashes(Vec<Hash<32>>),
+    /// Transactions in JSON format with metadata (`expand` without `binary`)
+    Expanded(Vec<Value>),
+    Binary(Vec<BinaryTransaction>),
+}
+
+impl<'de> Deserialize<'de> for LedgerTransactions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let list = Vec::<Value>::deserialize(deserializer)?;
+        match list.first() {
+            None | Some(Value::String(_)) => Ok(LedgerTransactions::Hashes(
+                list.into_iter().map(Hash::deserialize).collect::<Result<_, _>>().map_err(de::Error::custom)?
+            )),
+            Some(first) if first.get("tx_blob").is_some() => Ok(LedgerTransactions::Binary(
+                list.into_iter().map(BinaryTransaction::deserialize).collect::<Result<_, _>>().map_err(de::Error::custom)?
+            )),
+            Some(_) => Ok(LedgerTransactions::Expanded(list)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerHeader {
+    pub account_hash: Hash<32>,
+    pub close_flags: u8,
+    /// Seconds since the Ripple Epoch
+    pub close_time: u32,
+    pub close_time_human: Option<String>,
+    pub close_time_resolution: u8,
+    pub closed: bool,
+    pub ledger_hash: Hash<32>,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub ledger_index: u64,
+    pub parent_close_time: u32,
+    pub parent_hash: Hash<32>,
+    /// Total XRP in existence, in drops
+    #[serde(deserialize_with = "u64_or_string")]
+    pub total_coins: u64,
+    pub transaction_hash: Hash<32>,
+    pub transactions: Option<LedgerTransactions>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerQueueEntry {
+    pub account: Address,
+    /// Transaction hash, or the transaction itself if `expand` was set.
+    pub tx: Value,
+    pub retries_remaining: u32,
+    pub preflight_result: String,
+    pub last_result: Option<String>,
+    pub auth_change: Option<bool>,
+    #[serde(default, with = "crate::types::option_xrp")]
+    pub fee: Option<u64>,
+    pub fee_level: Option<String>,
+    #[serde(default, with = "crate::types::option_xrp")]
+    pub max_spend_drops: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerResponse {
+    pub ledger: LedgerHeader,
+    #[serde(flatten)]
+    pub ledger_for_response: LedgerForResponse,
+    pub queue_data: Option<Vec<LedgerQueueEntry>>,
+    pub validated: Option<bool>,
+}
+
+pub async fn ledger<'a, A>(api: &'a A, data: &'a LedgerRequest)
+    -> Result<TypedResponse<LedgerResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "ledger",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+/// Object changed by a ledger (Clio `ledger` with `diff`)
+#[derive(Debug)]
+pub enum LedgerDiffObject {
+    Deleted,
+    Parsed(LedgerEntry),
+    Binary(Vec<u8>),
+}
+
+#[derive(Debug)]
+pub struct LedgerDiffEntry {
+    pub object_id: Hash<32>,
+    pub object: LedgerDiffObject,
+}
+
+impl<'de> Deserialize<'de> for LedgerDiffEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct LedgerDiffEntry2 {
+            pub object_id: Hash<32>,
+            pub object: Value,
+        }
+        let value: LedgerDiffEntry2 = LedgerDiffEntry2::deserialize(deserializer)?;
+        let object = match value.object {
+            Value::String(s) if s.is_empty() => LedgerDiffObject::Deleted,
+            Value::String(s) => LedgerDiffObject::Binary(hex::decode(s).map_err(de::Error::custom)?),
+            object => LedgerDiffObject::Parsed(LedgerEntry::deserialize(object).map_err(de::Error::custom)?),
+        };
+        Ok(LedgerDiffEntry {
+            object_id: value.object_id,
+            object,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerDiffResponse {
+    pub ledger: LedgerHeader,
+    pub diff: Vec<LedgerDiffEntry>,
+    #[serde(flatten)]
+    pub ledger_for_response: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+/// `ledger` with the objects changed by the ledger (Clio only)
+///
+/// `data.binary` applies to the changed objects as well.
+pub async fn ledger_diff<'a, A>(api: &ClioApi<'a, A>, data: &'a LedgerRequest)
+    -> Result<TypedResponse<LedgerDiffResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let mut request: Request = (&TypedRequest {
+        command: "ledger",
+        api_version: Some(1),
+        data,
+    }).try_into().map_err(de::Error::custom)?;
+    if let Value::Object(params) = &mut request.params {
+        params.insert("diff".to_owned(), Value::Bool(true));
+    }
+    Ok(api.api().call(request).await?.try_into()?)
+}
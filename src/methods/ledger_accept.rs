@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerAcceptResponse {
+    /// The new open ledger
+    pub ledger_current_index: u32,
+}
+
+/// Close the current ledger and open a new one (admin only, stand-alone mode).
+pub async fn ledger_accept<A>(api: &AdminApi<'_, A>)
+    -> Result<TypedResponse<LedgerAcceptResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "ledger_accept",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
@@ -0,0 +1,47 @@
+use serde::{de, Deserialize, Serialize};
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Default, Serialize)]
+pub struct LedgerCleanerRequest {
+    /// Check only this ledger.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ledger: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ledger: Option<u32>,
+    /// Fix nodes even if they seem correct.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full: Option<bool>,
+    /// Fix transactions in the SQL database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_txns: Option<bool>,
+    /// Check the ledger state nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_nodes: Option<bool>,
+    /// Stop the cleaner and ignore the other fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerCleanerResponse {
+    pub message: String,
+}
+
+/// Configure the ledger cleaner, that fixes corrupted ledger data (admin only).
+pub async fn ledger_cleaner<'a, A>(api: &AdminApi<'a, A>, data: &'a LedgerCleanerRequest)
+    -> Result<TypedResponse<LedgerCleanerResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "ledger_cleaner",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.api().call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
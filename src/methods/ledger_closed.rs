@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerClosedResponse {
+    pub ledger_hash: Hash<32>,
+    pub ledger_index: u32,
+}
+
+/// The most recently closed ledger (not necessarily validated)
+pub async fn ledger_closed<A>(api: &A) -> Result<TypedResponse<LedgerClosedResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "ledger_closed",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerCurrentResponse {
+    pub ledger_current_index: u32,
+}
+
+/// The current in-progress ledger
+pub async fn ledger_current<A>(api: &A) -> Result<TypedResponse<LedgerCurrentResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "ledger_current",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
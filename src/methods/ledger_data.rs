@@ -0,0 +1,101 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::methods::ledger_entry::LedgerEntryNode;
+use crate::objects::ledger_entry::{LedgerEntry, LedgerEntryType};
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest};
+
+#[derive(Debug)]
+pub struct LedgerDataRequest {
+    pub ledger: LedgerForRequest,
+    /// Return objects in binary format.
+    pub binary: bool,
+    /// If set, return only objects of this type.
+    pub r#type: Option<LedgerEntryType>,
+    pub limit: Option<u32>,
+}
+
+impl Serialize for LedgerDataRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct LedgerDataRequest2<'a> {
+            #[serde(flatten)]
+            pub ledger: &'a LedgerForRequest,
+            pub binary: Option<bool>,
+            #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+            pub r#type: Option<LedgerEntryType>,
+            pub limit: Option<u32>,
+        }
+        LedgerDataRequest2 {
+            ledger: &self.ledger,
+            binary: if self.binary { Some(true) } else { None },
+            r#type: self.r#type,
+            limit: self.limit,
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LedgerDataResponse {
+    pub ledger_hash: Hash<32>,
+    pub ledger_index: u32,
+}
+
+#[derive(Debug)]
+pub struct LedgerDataPaginator {
+    /// Object ID
+    pub index: Hash<32>,
+    pub node: LedgerEntryNode,
+}
+
+impl<'de> Deserialize<'de> for LedgerDataPaginator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let value = Value::deserialize(deserializer)?;
+        let index = Hash::deserialize(value.get("index").ok_or_else(|| de::Error::missing_field("index"))?)
+            .map_err(de::Error::custom)?;
+        // In binary mode, the object is `{"data": "...", "index": "..."}`.
+        let node = if let Some(data) = value.get("data") {
+            LedgerEntryNode::Binary(crate::types::blob::deserialize(data).map_err(de::Error::custom)?)
+        } else {
+            LedgerEntryNode::Parsed(LedgerEntry::deserialize(value).map_err(de::Error::custom)?)
+        };
+        Ok(LedgerDataPaginator {
+            index,
+            node,
+        })
+    }
+}
+
+impl<'a> PaginatorExtractor<'a> for LedgerDataPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("state").ok_or::<XrplError>(de::Error::missing_field("state"))
+    }
+}
+
+/// Iterate over all objects of a ledger.
+///
+/// The subsequent pages are requested from the same ledger as the first one,
+/// even if `data.ledger` is not a fixed ledger (such as `LedgerForRequest::Validated`).
+pub async fn ledger_data<'a, A>(
+    api: &'a A,
+    data: &'a LedgerDataRequest,
+) -> Result<(TypedResponse<LedgerDataResponse>, Paginator<'a, A, LedgerDataPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "ledger_data",
+        api_version: Some(1),
+        data,
+    };
+    let (response, mut paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    let response: TypedResponse<LedgerDataResponse> = response.try_into()?;
+    if !matches!(data.ledger, LedgerForRequest::Index(_) | LedgerForRequest::Hash(_)) {
+        paginator.set_param("ledger_index", response.result.ledger_index.into());
+    }
+    Ok((response, paginator))
+}
@@ -0,0 +1,130 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::ledger_entry::LedgerEntry;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest, LedgerForResponse};
+
+/// How to find the ledger object
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntrySelector {
+    /// Object ID
+    Index(Hash<32>),
+    AccountRoot(Address),
+    Offer {
+        account: Address,
+        seq: u32,
+    },
+    /// Trust line between two accounts in the given currency
+    RippleState {
+        accounts: [Address; 2],
+        currency: String,
+    },
+    Escrow {
+        owner: Address,
+        seq: u32,
+    },
+    PaymentChannel(Hash<32>),
+    Check(Hash<32>),
+    Ticket {
+        account: Address,
+        ticket_seq: u32,
+    },
+    NftPage(Hash<32>),
+    DepositPreauth {
+        owner: Address,
+        authorized: Address,
+    },
+    /// Either `owner` or `dir_root` must be specified.
+    Directory {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        owner: Option<Address>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dir_root: Option<Hash<32>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sub_index: Option<u64>,
+    },
+}
+
+#[derive(Debug)]
+pub struct LedgerEntryRequest {
+    pub selector: LedgerEntrySelector,
+    pub ledger: LedgerForRequest,
+    /// Return the object in binary format.
+    pub binary: bool,
+}
+
+impl Serialize for LedgerEntryRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct LedgerEntryRequest2<'a> {
+            #[serde(flatten)]
+            pub selector: &'a LedgerEntrySelector,
+            #[serde(flatten)]
+            pub ledger: &'a LedgerForRequest,
+            pub binary: Option<bool>,
+        }
+        LedgerEntryRequest2 {
+            selector: &self.selector,
+            ledger: &self.ledger,
+            binary: if self.binary { Some(true) } else { None },
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug)]
+pub enum LedgerEntryNode {
+    Parsed(LedgerEntry),
+    Binary(Vec<u8>),
+}
+
+#[derive(Debug)]
+pub struct LedgerEntryResponse {
+    /// Object ID
+    pub index: Hash<32>,
+    pub node: LedgerEntryNode,
+    pub ledger: LedgerForResponse,
+    pub validated: bool,
+}
+
+impl<'de> Deserialize<'de> for LedgerEntryResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Debug, Deserialize)]
+        struct LedgerEntryResponse2 {
+            pub index: Hash<32>,
+            pub node: Option<LedgerEntry>,
+            #[serde(default, with = "crate::types::option_blob")]
+            pub node_binary: Option<Vec<u8>>,
+            #[serde(flatten)]
+            pub ledger: LedgerForResponse,
+            pub validated: Option<bool>,
+        }
+        let value: LedgerEntryResponse2 = LedgerEntryResponse2::deserialize(deserializer)?;
+        let node = match (value.node, value.node_binary) {
+            (Some(node), _) => LedgerEntryNode::Parsed(node),
+            (None, Some(node_binary)) => LedgerEntryNode::Binary(node_binary),
+            (None, None) => return Err(de::Error::missing_field("node")),
+        };
+        Ok(LedgerEntryResponse {
+            index: value.index,
+            node,
+            ledger: value.ledger,
+            validated: value.validated == Some(true),
+        })
+    }
+}
+
+pub async fn ledger_entry<'a, A>(api: &'a A, data: &'a LedgerEntryRequest)
+    -> Result<TypedResponse<LedgerEntryResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "ledger_entry",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
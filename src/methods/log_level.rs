@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::{Request, TypedRequest};
+use crate::response::TypedResponse;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogSeverity {
+    Trace,
+    Debug,
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetLogLevelRequest {
+    pub severity: LogSeverity,
+    /// Log partition to change; all partitions if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LogLevelResponse {
+    /// Severity by partition; `base` is the default
+    pub levels: HashMap<String, String>,
+}
+
+/// Current log levels (admin only)
+pub async fn log_level<A>(api: &AdminApi<'_, A>) -> Result<TypedResponse<LogLevelResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "log_level",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
+
+/// Change the log level (admin only).
+pub async fn set_log_level<'a, A>(api: &AdminApi<'a, A>, data: &'a SetLogLevelRequest)
+    -> Result<(), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "log_level",
+        api_version: Some(1),
+        data,
+    };
+    api.api().call((&request).try_into().map_err(de::Error::custom)?).await?;
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::ValidationOrNodePublicKey;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Serialize)]
+pub struct ManifestRequest {
+    /// Master or ephemeral public key of the validator
+    pub public_key: ValidationOrNodePublicKey,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ManifestDetails {
+    pub domain: String,
+    pub ephemeral_key: ValidationOrNodePublicKey,
+    pub master_key: ValidationOrNodePublicKey,
+    pub seq: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ManifestResponse {
+    /// Absent if the server has no manifest for the key.
+    pub details: Option<ManifestDetails>,
+    /// Base64-encoded manifest
+    pub manifest: Option<String>,
+    pub requested: ValidationOrNodePublicKey,
+}
+
+/// The latest validator manifest known to the server
+pub async fn manifest<'a, A>(api: &'a A, data: &'a ManifestRequest)
+    -> Result<TypedResponse<ManifestResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "manifest",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -2,4 +2,51 @@ pub mod account_channels;
 pub mod account_currencies;
 pub mod account_info;
 pub mod account_lines;
-pub mod submit;
\ No newline at end of file
+pub mod account_nfts;
+pub mod account_objects;
+pub mod account_offers;
+pub mod amm_info;
+pub mod book_offers;
+pub mod can_delete;
+pub mod channel_authorize;
+pub mod channel_verify;
+pub mod connect;
+pub mod consensus_info;
+pub mod deposit_authorized;
+pub mod fee;
+pub mod feature;
+pub mod gateway_balances;
+pub mod ledger;
+pub mod ledger_accept;
+pub mod ledger_cleaner;
+pub mod ledger_closed;
+pub mod ledger_current;
+pub mod ledger_data;
+pub mod ledger_entry;
+pub mod log_level;
+pub mod manifest;
+pub mod mpt_holders;
+pub mod nft_buy_offers;
+pub mod nft_history;
+pub mod nft_info;
+pub mod nft_sell_offers;
+pub mod nfts_by_issuer;
+pub mod noripple_check;
+pub mod path_find;
+pub mod peers;
+pub mod ping;
+pub mod random;
+pub mod ripple_path_find;
+pub mod server_info;
+pub mod server_state;
+pub mod sign;
+pub mod sign_for;
+pub mod simulate;
+pub mod stop;
+pub mod submit;
+pub mod submit_multisigned;
+pub mod transaction_entry;
+pub mod tx;
+pub mod tx_history;
+pub mod validator_list_sites;
+pub mod validators;
@@ -0,0 +1,58 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::clio::ClioApi;
+use crate::connection::{Api, XrplError};
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{u64_or_string, Hash, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct MptHoldersRequest {
+    pub mpt_issuance_id: Hash<24>,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MptHolderPaginator {
+    pub account: Address,
+    pub flags: u32,
+    #[serde(deserialize_with = "u64_or_string")]
+    pub mpt_amount: u64,
+    pub mptoken_index: Hash<32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MptHoldersResponse {
+    pub mpt_issuance_id: Hash<24>,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for MptHolderPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("mptokens").ok_or::<XrplError>(de::Error::missing_field("mptokens"))
+    }
+}
+
+/// Holders of a multi-purpose token (Clio only)
+pub async fn mpt_holders<'a, A>(
+    api: &ClioApi<'a, A>,
+    data: &'a MptHoldersRequest,
+) -> Result<(TypedResponse<MptHoldersResponse>, Paginator<'a, A, MptHolderPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "mpt_holders",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api.api(), (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
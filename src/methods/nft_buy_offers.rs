@@ -0,0 +1,59 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::AnyAmount;
+use crate::objects::nftoken_offer::NFTokenOfferFlags;
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct NftOffersRequest {
+    pub nft_id: Hash<32>,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftOfferPaginator {
+    pub amount: AnyAmount,
+    pub flags: NFTokenOfferFlags,
+    pub nft_offer_index: Hash<32>,
+    pub owner: Address,
+    pub destination: Option<Address>,
+    pub expiration: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftOffersResponse {
+    pub nft_id: Hash<32>,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for NftOfferPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("offers").ok_or::<XrplError>(de::Error::missing_field("offers"))
+    }
+}
+
+pub async fn nft_buy_offers<'a, A>(
+    api: &'a A,
+    data: &'a NftOffersRequest,
+) -> Result<(TypedResponse<NftOffersResponse>, Paginator<'a, A, NftOfferPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "nft_buy_offers",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
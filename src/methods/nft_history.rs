@@ -0,0 +1,80 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use crate::clio::ClioApi;
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::{TransactionJson, TransactionMetadata};
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug)]
+pub struct NftHistoryRequest {
+    pub nft_id: Hash<32>,
+    /// Earliest ledger to include, `None` for the earliest available.
+    pub ledger_index_min: Option<u32>,
+    /// Latest ledger to include, `None` for the latest validated.
+    pub ledger_index_max: Option<u32>,
+    /// Oldest transactions first.
+    pub forward: bool,
+    pub limit: Option<u16>,
+}
+
+impl Serialize for NftHistoryRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct NftHistoryRequest2<'a> {
+            pub nft_id: &'a Hash<32>,
+            pub ledger_index_min: i64,
+            pub ledger_index_max: i64,
+            pub forward: Option<bool>,
+            pub limit: Option<u16>,
+        }
+        NftHistoryRequest2 {
+            nft_id: &self.nft_id,
+            ledger_index_min: self.ledger_index_min.map_or(-1, i64::from),
+            ledger_index_max: self.ledger_index_max.map_or(-1, i64::from),
+            forward: if self.forward { Some(true) } else { None },
+            limit: self.limit,
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftHistoryPaginator {
+    pub tx: TransactionJson,
+    pub meta: TransactionMetadata,
+    pub validated: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftHistoryResponse {
+    pub nft_id: Hash<32>,
+    pub ledger_index_min: u32,
+    pub ledger_index_max: u32,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for NftHistoryPaginator {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("transactions").ok_or::<XrplError>(de::Error::missing_field("transactions"))
+    }
+}
+
+/// Transactions affecting an NFT (Clio only)
+pub async fn nft_history<'a, A>(
+    api: &ClioApi<'a, A>,
+    data: &'a NftHistoryRequest,
+) -> Result<(TypedResponse<NftHistoryResponse>, Paginator<'a, A, NftHistoryPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "nft_history",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api.api(), (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
@@ -0,0 +1,51 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::Address;
+use crate::clio::ClioApi;
+use crate::connection::{Api, XrplError};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest};
+
+#[derive(Debug, Serialize)]
+pub struct NftInfoRequest {
+    pub nft_id: Hash<32>,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftInfo {
+    pub nft_id: Hash<32>,
+    pub ledger_index: u32,
+    pub owner: Address,
+    pub is_burned: bool,
+    pub flags: u32,
+    /// In units of 1/100000
+    pub transfer_fee: u16,
+    pub issuer: Address,
+    pub nft_taxon: u32,
+    pub nft_serial: u32,
+    #[serde(default, with = "crate::types::option_blob")]
+    pub uri: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftInfoResponse {
+    #[serde(flatten)]
+    pub nft: NftInfo,
+    pub validated: Option<bool>,
+}
+
+/// Current state of an NFT, including burned ones (Clio only)
+pub async fn nft_info<'a, A>(api: &ClioApi<'a, A>, data: &'a NftInfoRequest)
+    -> Result<TypedResponse<NftInfoResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "nft_info",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.api().call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
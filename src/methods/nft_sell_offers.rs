@@ -0,0 +1,22 @@
+use crate::connection::{Api, XrplError};
+use crate::methods::nft_buy_offers::{NftOfferPaginator, NftOffersRequest, NftOffersResponse};
+use crate::paginate::Paginator;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+pub async fn nft_sell_offers<'a, A>(
+    api: &'a A,
+    data: &'a NftOffersRequest,
+) -> Result<(TypedResponse<NftOffersResponse>, Paginator<'a, A, NftOfferPaginator>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "nft_sell_offers",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api, (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
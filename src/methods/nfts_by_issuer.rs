@@ -0,0 +1,53 @@
+use serde::{de, Deserialize, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::clio::ClioApi;
+use crate::connection::{Api, XrplError};
+use crate::methods::nft_info::NftInfo;
+use crate::paginate::{Paginator, PaginatorExtractor};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct NftsByIssuerRequest {
+    pub issuer: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nft_taxon: Option<u32>,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NftsByIssuerResponse {
+    pub issuer: Address,
+    pub nft_taxon: Option<u32>,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+impl<'a> PaginatorExtractor<'a> for NftInfo {
+    fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+        result.get("nfts").ok_or::<XrplError>(de::Error::missing_field("nfts"))
+    }
+}
+
+/// NFTs issued by an account, including burned ones (Clio only)
+pub async fn nfts_by_issuer<'a, A>(
+    api: &ClioApi<'a, A>,
+    data: &'a NftsByIssuerRequest,
+) -> Result<(TypedResponse<NftsByIssuerResponse>, Paginator<'a, A, NftInfo>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "nfts_by_issuer",
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) =
+        Paginator::start(api.api(), (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?;
+    Ok((response.try_into()?, paginator))
+}
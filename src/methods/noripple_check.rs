@@ -0,0 +1,208 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::{Amount, AnyAmount};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::txs::account_set::{AccountSetFlag, AccountSetTransaction, TRANSACTION_TYPE_ACCOUNT_SET};
+use crate::txs::trust_set::{TrustSetFlags, TrustSetTransaction, TRANSACTION_TYPE_TRUST_SET};
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoRippleCheckRole {
+    Gateway,
+    User,
+}
+
+#[derive(Debug)]
+pub struct NoRippleCheckRequest {
+    pub account: Address,
+    pub role: NoRippleCheckRole,
+    pub ledger: LedgerForRequest,
+    /// Whether to return the suggested transactions fixing the problems.
+    pub transactions: bool,
+    pub limit: Option<u16>,
+}
+
+impl Serialize for NoRippleCheckRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct NoRippleCheckRequest2<'a> {
+            pub account: &'a Address,
+            pub role: NoRippleCheckRole,
+            #[serde(flatten)]
+            pub ledger: &'a LedgerForRequest,
+            pub transactions: Option<bool>,
+            pub limit: Option<u16>,
+        }
+        NoRippleCheckRequest2 {
+            account: &self.account,
+            role: self.role,
+            ledger: &self.ledger,
+            transactions: if self.transactions { Some(true) } else { None },
+            limit: self.limit,
+        }.serialize(serializer)
+    }
+}
+
+/// A transaction suggested by the server to fix a problem
+///
+/// The server fills in `Fee` and `Sequence`, so that the transaction can be signed as is.
+#[derive(Clone, Debug)]
+pub enum SuggestedTransaction {
+    AccountSet(AccountSetTransaction),
+    TrustSet(TrustSetTransaction),
+}
+
+impl<'de> Deserialize<'de> for SuggestedTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        #[serde(tag = "TransactionType")]
+        enum SuggestedTransaction2 {
+            AccountSet {
+                #[serde(rename = "Account")]
+                account: Address,
+                /// In drops, as a number
+                #[serde(rename = "Fee")]
+                #[serde(deserialize_with = "crate::types::u64_or_string")]
+                fee: u64,
+                #[serde(rename = "Sequence")]
+                sequence: u32,
+                #[serde(rename = "SetFlag")]
+                set_flag: Option<u32>,
+                #[serde(rename = "ClearFlag")]
+                clear_flag: Option<u32>,
+            },
+            TrustSet {
+                #[serde(rename = "Account")]
+                account: Address,
+                /// In drops, as a number
+                #[serde(rename = "Fee")]
+                #[serde(deserialize_with = "crate::types::u64_or_string")]
+                fee: u64,
+                #[serde(rename = "Sequence")]
+                sequence: u32,
+                #[serde(rename = "Flags")]
+                flags: Option<u32>,
+                #[serde(rename = "LimitAmount")]
+                limit_amount: Amount,
+            },
+        }
+        fn account_set_flag<E: de::Error>(flag: Option<u32>) -> Result<Option<AccountSetFlag>, E> {
+            flag.map(AccountSetFlag::try_from).transpose()
+                .map_err(|flag| de::Error::custom(format!("unknown AccountSet flag {}", flag)))
+        }
+        Ok(match SuggestedTransaction2::deserialize(deserializer)? {
+            SuggestedTransaction2::AccountSet { account, fee, sequence, set_flag, clear_flag } =>
+                SuggestedTransaction::AccountSet(AccountSetTransaction {
+                    account,
+                    transaction_type: TRANSACTION_TYPE_ACCOUNT_SET,
+                    fee: Some(AnyAmount::Xrp(fee)),
+                    sequence: Some(sequence),
+                    account_txn_id: None,
+                    flags: None,
+                    last_ledger_sequence: None,
+                    source_tag: None,
+                    public_key: None,
+                    ticket_sequence: None,
+                    signature: None,
+                    set_flag: account_set_flag(set_flag)?,
+                    clear_flag: account_set_flag(clear_flag)?,
+                    domain: None,
+                    email_hash: None,
+                    message_key: None,
+                    transfer_rate: None,
+                    tick_size: None,
+                    nftoken_minter: None,
+                }),
+            SuggestedTransaction2::TrustSet { account, fee, sequence, flags, limit_amount } =>
+                SuggestedTransaction::TrustSet(TrustSetTransaction {
+                    account,
+                    transaction_type: TRANSACTION_TYPE_TRUST_SET,
+                    fee: Some(AnyAmount::Xrp(fee)),
+                    sequence: Some(sequence),
+                    account_txn_id: None,
+                    flags: TrustSetFlags(flags.unwrap_or(0)),
+                    last_ledger_sequence: None,
+                    source_tag: None,
+                    public_key: None,
+                    ticket_sequence: None,
+                    signature: None,
+                    limit_amount,
+                    quality_in: None,
+                    quality_out: None,
+                }),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NoRippleCheckResponse {
+    /// Human-readable descriptions of the problems found
+    pub problems: Vec<String>,
+    /// Present if `transactions` was requested.
+    pub transactions: Option<Vec<SuggestedTransaction>>,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+pub async fn noripple_check<'a, A>(api: &'a A, data: &'a NoRippleCheckRequest)
+    -> Result<TypedResponse<NoRippleCheckResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "noripple_check",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::methods::noripple_check::SuggestedTransaction;
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::account_set::AccountSetFlag;
+    use crate::txs::trust_set::TrustSetFlags;
+
+    #[test]
+    fn test_deserialize_suggested_transactions() {
+        let transactions: Vec<SuggestedTransaction> = serde_json::from_value(json!([
+            {
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Fee": 10,
+                "Sequence": 1406,
+                "SetFlag": 8,
+                "TransactionType": "AccountSet"
+            },
+            {
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Fee": 11,
+                "Flags": 262144,
+                "LimitAmount": {
+                    "currency": "USD",
+                    "issuer": "rBuTMT7Fkvkd9GBDDkc6nBXSJWvbCMhZga",
+                    "value": "5"
+                },
+                "Sequence": 1407,
+                "TransactionType": "TrustSet"
+            }
+        ])).unwrap();
+        let SuggestedTransaction::AccountSet(account_set) = &transactions[0] else {
+            panic!("expected AccountSet");
+        };
+        assert!(matches!(account_set.fee, Some(AnyAmount::Xrp(10))));
+        assert_eq!(account_set.sequence, Some(1406));
+        assert_eq!(account_set.set_flag, Some(AccountSetFlag::DefaultRipple));
+        let SuggestedTransaction::TrustSet(trust_set) = &transactions[1] else {
+            panic!("expected TrustSet");
+        };
+        assert_eq!(trust_set.sequence, Some(1407));
+        assert_eq!(trust_set.flags, TrustSetFlags::CLEAR_NO_RIPPLE);
+        assert_eq!(trust_set.limit_amount.currency, "USD");
+    }
+}
@@ -0,0 +1,120 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use lazy_static::lazy_static;
+use serde::{de, Deserialize, Serialize, Serializer};
+use serde_json::{json, Value};
+use tokio_stream::Stream;
+use crate::hashes::Address;
+use crate::connection::{Api, WebSocketApi, XrplError};
+use crate::methods::ripple_path_find::{PathAlternative, PathStep};
+use crate::objects::amount::AnyAmount;
+use crate::request::{Request, TypedRequest};
+use crate::response::TypedResponse;
+
+#[derive(Debug)]
+pub struct PathFindCreateRequest {
+    pub source_account: Address,
+    pub destination_account: Address,
+    pub destination_amount: AnyAmount,
+    pub send_max: Option<AnyAmount>,
+    /// Paths to start the search from
+    pub paths: Option<Vec<Vec<PathStep>>>,
+}
+
+impl Serialize for PathFindCreateRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct PathFindCreateRequest2<'a> {
+            pub subcommand: &'static str,
+            pub source_account: &'a Address,
+            pub destination_account: &'a Address,
+            pub destination_amount: &'a AnyAmount,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub send_max: Option<&'a AnyAmount>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub paths: Option<&'a Vec<Vec<PathStep>>>,
+        }
+        PathFindCreateRequest2 {
+            subcommand: "create",
+            source_account: &self.source_account,
+            destination_account: &self.destination_account,
+            destination_amount: &self.destination_amount,
+            send_max: self.send_max.as_ref(),
+            paths: self.paths.as_ref(),
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PathFindResponse {
+    pub alternatives: Vec<PathAlternative>,
+    pub source_account: Address,
+    pub destination_account: Address,
+    pub destination_amount: AnyAmount,
+    /// `false` if the search is not yet complete, so that better paths may come in updates.
+    pub full_reply: Option<bool>,
+    /// Present in the response to `close`.
+    pub closed: Option<bool>,
+}
+
+lazy_static! {
+    static ref PATH_FIND_KEY: String = "path_find".to_string();
+}
+
+/// Path finding request that is kept open by the server (WebSocket only)
+///
+/// The server sends updated alternatives when they change: this is a `Stream` of them.
+/// Other asynchronous messages are left for `WebSocketApi::next_event`.
+/// Only one path finding request can be open per connection, a new one replaces the old one.
+pub struct PathFind<'a> {
+    api: &'a WebSocketApi,
+    /// Wait for the next update, kept across polls.
+    next_update: Option<Pin<Box<dyn Future<Output = Result<Value, XrplError>> + 'a>>>,
+}
+
+impl<'a> PathFind<'a> {
+    pub async fn create(api: &'a WebSocketApi, data: &'a PathFindCreateRequest)
+        -> Result<(TypedResponse<PathFindResponse>, PathFind<'a>), XrplError>
+    {
+        let request = TypedRequest {
+            command: "path_find",
+            api_version: Some(1),
+            data,
+        };
+        let response = api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?;
+        Ok((response, Self { api, next_update: None }))
+    }
+    async fn subcommand(&self, subcommand: &str) -> Result<TypedResponse<PathFindResponse>, XrplError> {
+        let request = Request {
+            command: "path_find",
+            api_version: Some(1),
+            params: json!({"subcommand": subcommand}),
+        };
+        self.api.call(request).await?.try_into()
+    }
+    /// Current alternatives
+    pub async fn status(&self) -> Result<TypedResponse<PathFindResponse>, XrplError> {
+        self.subcommand("status").await
+    }
+    pub async fn close(self) -> Result<TypedResponse<PathFindResponse>, XrplError> {
+        self.subcommand("close").await
+    }
+}
+
+impl<'a> Stream for PathFind<'a> {
+    type Item = Result<PathFindResponse, XrplError>;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let api = this.api;
+        let next_update = this.next_update
+            .get_or_insert_with(|| Box::pin(api.next_event_of_type(&PATH_FIND_KEY)));
+        match next_update.as_mut().poll(cx) {
+            Poll::Ready(event) => {
+                this.next_update = None;
+                Poll::Ready(Some(event.and_then(|event| Ok(PathFindResponse::deserialize(event)?))))
+            },
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug, Deserialize)]
+pub struct Peer {
+    /// IP address and port
+    pub address: String,
+    /// Whether the peer is in our cluster.
+    pub cluster: Option<bool>,
+    /// Cluster name, if configured.
+    pub name: Option<String>,
+    pub complete_ledgers: Option<String>,
+    pub inbound: Option<bool>,
+    /// In milliseconds
+    pub latency: Option<u32>,
+    /// Hash of the peer's latest closed ledger
+    pub ledger: Option<Hash<32>>,
+    pub load: Option<u32>,
+    pub protocol: Option<String>,
+    /// Node public key (base58)
+    pub public_key: Option<String>,
+    /// `"insane"` or `"unknown"` if the peer is not following the network.
+    pub sanity: Option<String>,
+    pub status: Option<String>,
+    /// In seconds
+    pub uptime: Option<u64>,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PeersResponse {
+    /// Cluster members by public key
+    pub cluster: Option<Value>,
+    pub peers: Option<Vec<Peer>>,
+}
+
+/// Peers connected to the server (admin only)
+pub async fn peers<A>(api: &AdminApi<'_, A>) -> Result<TypedResponse<PeersResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "peers",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
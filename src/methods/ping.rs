@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct PingResponse {
+    /// Present for admin and identified connections only.
+    pub role: Option<String>,
+    pub unlimited: Option<bool>,
+}
+
+/// Check that the server is responsive.
+pub async fn ping<A>(api: &A) -> Result<TypedResponse<PingResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "ping",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+#[derive(Debug, Deserialize)]
+pub struct RandomResponse {
+    pub random: Hash<32>,
+}
+
+/// Random number generated by the server (don't use it for secret keys).
+pub async fn random<A>(api: &A) -> Result<TypedResponse<RandomResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "random",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
@@ -0,0 +1,64 @@
+use serde::{de, Deserialize, Serialize};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::objects::amount::{AnyAmount, Issue};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{LedgerForRequest, LedgerForResponse};
+
+/// Step of a payment path: an account to ripple through or a currency (and issuer) to convert to
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PathStep {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<Address>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PathAlternative {
+    pub paths_computed: Vec<Vec<PathStep>>,
+    /// Amount the sender would pay
+    pub source_amount: AnyAmount,
+    /// Present if different from the requested amount (with `send_max`).
+    pub destination_amount: Option<AnyAmount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RipplePathFindRequest {
+    pub source_account: Address,
+    pub destination_account: Address,
+    pub destination_amount: AnyAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_max: Option<AnyAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_currencies: Option<Vec<Issue>>,
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RipplePathFindResponse {
+    pub alternatives: Vec<PathAlternative>,
+    pub destination_account: Address,
+    pub destination_currencies: Vec<String>,
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub validated: Option<bool>,
+}
+
+/// Find payment paths in a single request (see `path_find` for a WebSocket subscription).
+pub async fn ripple_path_find<'a, A>(api: &'a A, data: &'a RipplePathFindRequest)
+    -> Result<TypedResponse<RipplePathFindResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "ripple_path_find",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -0,0 +1,120 @@
+use std::ops::RangeInclusive;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+use crate::hashes::ValidationOrNodePublicKey;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+/// Parse `complete_ledgers` like `"32570-6595042,6595044"` (or `"empty"`).
+pub fn parse_complete_ledgers(s: &str) -> Result<Vec<RangeInclusive<u32>>, std::num::ParseIntError> {
+    if s == "empty" || s.is_empty() {
+        return Ok(Vec::new());
+    }
+    s.split(',').map(|range| {
+        Ok(if let Some((start, end)) = range.split_once('-') {
+            start.trim().parse()? ..= end.trim().parse()?
+        } else {
+            let index = range.trim().parse()?;
+            index ..= index
+        })
+    }).collect()
+}
+
+pub(crate) fn complete_ledgers<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<RangeInclusive<u32>>, D::Error> {
+    parse_complete_ledgers(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LastClose {
+    pub converge_time_s: f64,
+    pub proposers: u32,
+}
+
+/// Amounts are in XRP (not drops) in `server_info`.
+#[derive(Debug, Deserialize)]
+pub struct ValidatedLedgerInfo {
+    /// Seconds since the ledger was validated
+    pub age: u32,
+    pub base_fee_xrp: f64,
+    pub hash: Hash<32>,
+    pub reserve_base_xrp: f64,
+    pub reserve_inc_xrp: f64,
+    pub seq: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServerInfo {
+    /// Absent on Clio servers, as well as `io_latency_ms`, `last_close` and `server_state`.
+    pub build_version: Option<String>,
+    /// Ranges of ledgers available on this server
+    #[serde(deserialize_with = "complete_ledgers")]
+    pub complete_ledgers: Vec<RangeInclusive<u32>>,
+    pub hostid: Option<String>,
+    pub io_latency_ms: Option<u32>,
+    pub jq_trans_overflow: Option<String>,
+    pub last_close: Option<LastClose>,
+    /// Multiplier to the transaction cost (combination of `load_factor_*` fields)
+    pub load_factor: f64,
+    pub load_factor_local: Option<f64>,
+    pub load_factor_net: Option<f64>,
+    pub load_factor_cluster: Option<f64>,
+    pub load_factor_fee_escalation: Option<f64>,
+    pub load_factor_fee_queue: Option<f64>,
+    pub load_factor_server: Option<f64>,
+    pub network_id: Option<u32>,
+    pub peers: Option<u32>,
+    #[serde(default, with = "crate::hashes::option_base58")]
+    pub pubkey_node: Option<ValidationOrNodePublicKey>,
+    #[serde(default, deserialize_with = "validator_public_key")]
+    pub pubkey_validator: Option<ValidationOrNodePublicKey>,
+    /// Such as `full`, `syncing` or `proposing`
+    pub server_state: Option<String>,
+    pub server_state_duration_us: Option<String>,
+    pub time: Option<String>,
+    pub uptime: Option<u64>,
+    pub validated_ledger: Option<ValidatedLedgerInfo>,
+    pub validation_quorum: Option<u32>,
+    /// Present only on Clio servers (see also `crate::clio`).
+    pub clio_version: Option<String>,
+}
+
+/// `"none"` for non-validators.
+fn validator_public_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<ValidationOrNodePublicKey>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(key) if key != "none" => Ok(Some(ValidationOrNodePublicKey::decode(&key).map_err(de::Error::custom)?)),
+        _ => Ok(None),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServerInfoResponse {
+    pub info: ServerInfo,
+}
+
+/// Human-readable server status (see `server_state` for machine-readable one).
+pub async fn server_info<A>(api: &A) -> Result<TypedResponse<ServerInfoResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "server_info",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::methods::server_info::parse_complete_ledgers;
+
+    #[test]
+    fn test_parse_complete_ledgers() {
+        assert_eq!(parse_complete_ledgers("empty").unwrap(), vec![]);
+        assert_eq!(parse_complete_ledgers("32570-6595042").unwrap(), vec![32570..=6595042]);
+        assert_eq!(parse_complete_ledgers("1-5,7,9-10").unwrap(), vec![1..=5, 7..=7, 9..=10]);
+        assert!(parse_complete_ledgers("1-x").is_err());
+    }
+}
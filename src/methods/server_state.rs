@@ -0,0 +1,61 @@
+use std::ops::RangeInclusive;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::hashes::ValidationOrNodePublicKey;
+use crate::connection::{Api, XrplError};
+use crate::methods::server_info::complete_ledgers;
+use crate::request::Request;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+/// Amounts are in drops in `server_state`.
+#[derive(Debug, Deserialize)]
+pub struct ValidatedLedgerState {
+    pub base_fee: u64,
+    /// Seconds since the Ripple Epoch
+    pub close_time: u32,
+    pub hash: Hash<32>,
+    pub reserve_base: u64,
+    pub reserve_inc: u64,
+    pub seq: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServerState {
+    pub build_version: String,
+    #[serde(deserialize_with = "complete_ledgers")]
+    pub complete_ledgers: Vec<RangeInclusive<u32>>,
+    pub io_latency_ms: u32,
+    /// Divide `load_factor` by `load_base` to get the transaction cost multiplier.
+    pub load_base: u32,
+    pub load_factor: u32,
+    pub load_factor_fee_escalation: Option<u32>,
+    pub load_factor_fee_queue: Option<u32>,
+    pub load_factor_fee_reference: Option<u32>,
+    pub load_factor_server: Option<u32>,
+    pub network_id: Option<u32>,
+    pub peers: Option<u32>,
+    #[serde(default, with = "crate::hashes::option_base58")]
+    pub pubkey_node: Option<ValidationOrNodePublicKey>,
+    pub server_state: String,
+    pub uptime: Option<u64>,
+    pub validated_ledger: Option<ValidatedLedgerState>,
+    pub validation_quorum: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServerStateResponse {
+    pub state: ServerState,
+}
+
+pub async fn server_state<A>(api: &A) -> Result<TypedResponse<ServerStateResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "server_state",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.call(request).await?.try_into()?)
+}
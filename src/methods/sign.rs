@@ -0,0 +1,95 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::TransactionJson;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Secret to sign with; sent to the server, so use only with a server you trust.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SigningSecret {
+    /// Base58 seed, assumes `secp256k1` key type.
+    Secret(String),
+    /// Base58 seed
+    Seed(String),
+    SeedHex(String),
+    Passphrase(String),
+}
+
+#[derive(Debug)]
+pub struct SignRequest {
+    /// Transaction to sign, in JSON format
+    ///
+    /// The server takes no binary transactions here, and the transaction structs of `crate::txs`
+    /// have only the binary format. To sign them, use `crate::txs::sign_transaction` instead.
+    pub tx_json: Value,
+    pub secret: SigningSecret,
+    /// Must be `None` for `SigningSecret::Secret`.
+    pub key_type: Option<KeyType>,
+    /// Don't fill in `Sequence`, `Fee` and so on from the ledger.
+    pub offline: bool,
+    pub build_path: bool,
+    pub fee_mult_max: Option<u32>,
+    pub fee_div_max: Option<u32>,
+}
+
+impl Serialize for SignRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct SignRequest2<'a> {
+            pub tx_json: &'a Value,
+            #[serde(flatten)]
+            pub secret: &'a SigningSecret,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub key_type: Option<KeyType>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub offline: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub build_path: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub fee_mult_max: Option<u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub fee_div_max: Option<u32>,
+        }
+        SignRequest2 {
+            tx_json: &self.tx_json,
+            secret: &self.secret,
+            key_type: self.key_type,
+            offline: if self.offline { Some(true) } else { None },
+            build_path: if self.build_path { Some(true) } else { None },
+            fee_mult_max: self.fee_mult_max,
+            fee_div_max: self.fee_div_max,
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignResponse {
+    #[serde(with = "crate::types::blob")]
+    pub tx_blob: Vec<u8>,
+    pub tx_json: TransactionJson,
+}
+
+/// Sign a transaction by the server (admin or trusted method).
+///
+/// Prefer local signing by `crate::txs::sign_transaction`, that does not send the secret.
+pub async fn sign<'a, A>(api: &'a A, data: &'a SignRequest)
+    -> Result<TypedResponse<SignResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "sign",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
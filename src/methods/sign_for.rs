@@ -0,0 +1,38 @@
+use serde::{de, Serialize};
+use serde_json::Value;
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError};
+use crate::methods::sign::{KeyType, SignResponse, SigningSecret};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Serialize)]
+pub struct SignForRequest {
+    /// The signer, that is the account providing the signature
+    pub account: Address,
+    /// Transaction to sign, in JSON format, possibly already having some `Signers`
+    ///
+    /// As for `sign`, the server takes no binary transactions here. To multi-sign transaction structs
+    /// of `crate::txs` locally, use `crate::txs::multisign`.
+    pub tx_json: Value,
+    #[serde(flatten)]
+    pub secret: SigningSecret,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<KeyType>,
+}
+
+/// Add a multi-signature to a transaction by the server (admin or trusted method).
+///
+/// The returned `tx_json` has the new signature added to `Signers`.
+pub async fn sign_for<'a, A>(api: &'a A, data: &'a SignForRequest)
+    -> Result<TypedResponse<SignResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "sign_for",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
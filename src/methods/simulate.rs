@@ -0,0 +1,83 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use xrpl_binary_codec::serializer::HASH_PREFIX_TRANSACTION;
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::{TransactionJson, TransactionMetadata};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::txs::Transaction;
+
+#[derive(Debug)]
+pub struct SimulateRequest {
+    /// Unsigned transaction in binary format (without hash prefix)
+    pub tx_blob: Vec<u8>,
+    /// Return the transaction and metadata in binary format.
+    pub binary: bool,
+}
+
+impl Serialize for SimulateRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct SimulateRequest2 {
+            pub tx_blob: String,
+            pub binary: Option<bool>,
+        }
+        SimulateRequest2 {
+            tx_blob: hex::encode_upper(&self.tx_blob),
+            binary: if self.binary { Some(true) } else { None },
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimulateResponse {
+    /// Such as `tesSUCCESS`
+    pub engine_result: String,
+    pub engine_result_code: i32,
+    pub engine_result_message: String,
+    /// Always `false`: the transaction is not submitted.
+    pub applied: bool,
+    /// The ledger the transaction was applied to
+    pub ledger_index: u32,
+    /// Predicted metadata (JSON mode)
+    pub meta: Option<TransactionMetadata>,
+    #[serde(default, with = "crate::types::option_blob")]
+    pub meta_blob: Option<Vec<u8>>,
+    /// The transaction with the auto-filled fields (JSON mode)
+    pub tx_json: Option<TransactionJson>,
+    #[serde(default, with = "crate::types::option_blob")]
+    pub tx_blob: Option<Vec<u8>>,
+}
+
+pub async fn simulate_blob<'a, A>(api: &'a A, data: &'a SimulateRequest)
+    -> Result<TypedResponse<SimulateResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "simulate",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+/// Run a transaction without submitting it.
+///
+/// `tx` is sent unsigned: its `signature` and `public_key` are ignored.
+/// `Fee` and `Sequence` may be left empty to be auto-filled by the server.
+pub async fn simulate<A, T>(api: &A, tx: &T, binary: bool)
+    -> Result<TypedResponse<SimulateResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>,
+          T: Transaction,
+{
+    let mut ser = Vec::new();
+    // Empty `SigningPubKey` and no `TxnSignature`, as in a multi-signed transaction without signers.
+    T::serialize_multisigned(tx, &HASH_PREFIX_TRANSACTION, &[], &mut ser)
+        .map_err(|_| de::Error::custom("Cannot serialize a transaction (internal error)"))?;
+    let request = SimulateRequest {
+        tx_blob: ser.split_off(HASH_PREFIX_TRANSACTION.len()),
+        binary,
+    };
+    simulate_blob(api, &request).await
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct StopResponse {
+    pub message: String,
+}
+
+/// Gracefully shut down the server (admin only).
+pub async fn stop<A>(api: &AdminApi<'_, A>) -> Result<TypedResponse<StopResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "stop",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
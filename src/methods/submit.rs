@@ -6,6 +6,7 @@ use crate::connection::{Api, XrplError};
 use crate::request::TypedRequest;
 use crate::response::TypedResponse;
 use crate::txs::{sign_transaction, Transaction};
+use crate::txs::multisign::{multisigned_blob, Signer};
 
 #[derive(Debug)]
 pub struct TransactionRequest {
@@ -77,4 +78,21 @@ pub async fn sign_and_submit<'a, A, T>(api: &A,
         fail_hard,
     };
     submit(api, &request).await
-}
\ No newline at end of file
+}
+
+/// Submit `tx` multi-signed by `signers` (see `crate::txs::multisign`) in binary format.
+pub async fn submit_multisigned_transaction<A, T>(api: &A,
+                                                  tx: &T,
+                                                  signers: &[Signer],
+                                                  fail_hard: bool)
+                                                  -> Result<TypedResponse<TransactionResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>,
+          T: Transaction,
+{
+    let request = TransactionRequest {
+        tx_blob: multisigned_blob(tx, signers).map_err(de::Error::custom)?,
+        fail_hard,
+    };
+    submit(api, &request).await
+}
@@ -0,0 +1,57 @@
+use serde::{de, Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::TransactionJson;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug)]
+pub struct SubmitMultisignedRequest {
+    /// Transaction in JSON format with a `Signers` array and empty `SigningPubKey` (see `txs::multisign`)
+    pub tx_json: Value,
+    pub fail_hard: bool,
+}
+
+impl Serialize for SubmitMultisignedRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct SubmitMultisignedRequest2<'a> {
+            pub tx_json: &'a Value,
+            pub fail_hard: Option<bool>,
+        }
+        SubmitMultisignedRequest2 {
+            tx_json: &self.tx_json,
+            fail_hard: if self.fail_hard { Some(true) } else { None },
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitMultisignedResponse {
+    pub engine_result: String,
+    pub engine_result_code: i32,
+    pub engine_result_message: String,
+    #[serde(with = "crate::types::blob")]
+    pub tx_blob: Vec<u8>,
+    pub tx_json: TransactionJson,
+}
+
+/// Submit a multi-signed transaction.
+///
+/// The server takes only JSON here. A multi-signed transaction struct of `crate::txs`
+/// is submitted in binary format by `submit` instead, see `crate::txs::multisign`.
+pub async fn submit_multisigned<'a, A>(api: &'a A, data: &'a SubmitMultisignedRequest)
+    -> Result<TypedResponse<SubmitMultisignedResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    if data.tx_json.get("Signers").and_then(Value::as_array).map_or(true, Vec::is_empty) {
+        return Err(XrplError::Message("submit_multisigned: no Signers in the transaction".to_owned()).into());
+    }
+    let request = TypedRequest {
+        command: "submit_multisigned",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -0,0 +1,37 @@
+use serde::{de, Deserialize, Serialize};
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::{TransactionJson, TransactionMetadata};
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::{Hash, LedgerForRequest, LedgerForResponse};
+
+#[derive(Debug, Serialize)]
+pub struct TransactionEntryRequest {
+    pub tx_hash: Hash<32>,
+    /// Must be a specific ledger (not `LedgerForRequest::Current`).
+    #[serde(flatten)]
+    pub ledger: LedgerForRequest,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionEntryResponse {
+    #[serde(flatten)]
+    pub ledger: LedgerForResponse,
+    pub metadata: TransactionMetadata,
+    pub tx_json: TransactionJson,
+    pub validated: Option<bool>,
+}
+
+/// Look up a transaction in a specific ledger.
+pub async fn transaction_entry<'a, A>(api: &'a A, data: &'a TransactionEntryRequest)
+    -> Result<TypedResponse<TransactionEntryResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "transaction_entry",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
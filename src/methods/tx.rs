@@ -0,0 +1,276 @@
+use std::ops::RangeInclusive;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use crate::hashes::Address;
+use crate::connection::{Api, XrplError, XrplStatusError};
+use crate::objects::amount::AnyAmount;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+use crate::types::Hash;
+
+/// Transaction in JSON format
+///
+/// The common fields are typed, the transaction type specific ones are left in `fields`.
+#[derive(Debug, Deserialize)]
+pub struct TransactionJson {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "TransactionType")]
+    pub transaction_type: String,
+    #[serde(rename = "Fee")]
+    #[serde(with = "crate::types::xrp")]
+    pub fee: u64,
+    #[serde(rename = "Sequence")]
+    pub sequence: u32,
+    #[serde(rename = "Flags")]
+    pub flags: Option<u32>,
+    #[serde(rename = "LastLedgerSequence")]
+    pub last_ledger_sequence: Option<u32>,
+    #[serde(rename = "TicketSequence")]
+    pub ticket_sequence: Option<u32>,
+    #[serde(rename = "SourceTag")]
+    pub source_tag: Option<u32>,
+    /// Empty for multi-signed transactions.
+    #[serde(rename = "SigningPubKey")]
+    #[serde(default, with = "crate::types::option_blob")]
+    pub signing_pub_key: Option<Vec<u8>>,
+    #[serde(rename = "TxnSignature")]
+    #[serde(default, with = "crate::types::option_blob")]
+    pub txn_signature: Option<Vec<u8>>,
+    pub hash: Option<Hash<32>>,
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AffectedNodeFields {
+    #[serde(rename = "LedgerEntryType")]
+    pub ledger_entry_type: String,
+    #[serde(rename = "LedgerIndex")]
+    pub ledger_index: Hash<32>,
+    #[serde(rename = "NewFields")]
+    pub new_fields: Option<Value>,
+    #[serde(rename = "FinalFields")]
+    pub final_fields: Option<Value>,
+    #[serde(rename = "PreviousFields")]
+    pub previous_fields: Option<Value>,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<Hash<32>>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub enum AffectedNode {
+    CreatedNode(AffectedNodeFields),
+    ModifiedNode(AffectedNodeFields),
+    DeletedNode(AffectedNodeFields),
+}
+
+/// `"unavailable"` (for old partial payments) is mapped to `None`.
+fn delivered_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<AnyAmount>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Value::String(s)) if s == "unavailable" => Ok(None),
+        Some(value) => Ok(Some(AnyAmount::deserialize(value).map_err(de::Error::custom)?)),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionMetadata {
+    #[serde(rename = "AffectedNodes")]
+    pub affected_nodes: Vec<AffectedNode>,
+    #[serde(rename = "TransactionIndex")]
+    pub transaction_index: u32,
+    /// Such as `tesSUCCESS`
+    #[serde(rename = "TransactionResult")]
+    pub transaction_result: String,
+    #[serde(default, deserialize_with = "delivered_amount")]
+    pub delivered_amount: Option<AnyAmount>,
+}
+
+#[derive(Clone, Debug)]
+pub enum TxSelector {
+    Hash(Hash<32>),
+    /// Compact transaction identifier, see `encode_ctid`
+    Ctid(u64),
+}
+
+/// Compact transaction identifier from the ledger index,
+/// the index of the transaction in the ledger and the network ID.
+///
+/// `None` if the ledger index does not fit into 28 bits.
+pub fn encode_ctid(ledger_index: u32, transaction_index: u16, network_id: u16) -> Option<u64> {
+    if ledger_index > 0x0FFF_FFFF {
+        return None;
+    }
+    Some(0xC000_0000_0000_0000
+        | ((ledger_index as u64) << 32)
+        | ((transaction_index as u64) << 16)
+        | network_id as u64)
+}
+
+#[derive(Debug)]
+pub struct TxRequest {
+    pub transaction: TxSelector,
+    /// Return the transaction and metadata in binary format.
+    pub binary: bool,
+    /// Ledger range to search
+    pub ledger_range: Option<RangeInclusive<u32>>,
+}
+
+impl Serialize for TxRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Debug, Serialize)]
+        struct TxRequest2<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub transaction: Option<&'a Hash<32>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub ctid: Option<String>,
+            pub binary: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub min_ledger: Option<u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub max_ledger: Option<u32>,
+        }
+        let (transaction, ctid) = match &self.transaction {
+            TxSelector::Hash(hash) => (Some(hash), None),
+            TxSelector::Ctid(ctid) => (None, Some(format!("{:016X}", ctid))),
+        };
+        TxRequest2 {
+            transaction,
+            ctid,
+            binary: if self.binary { Some(true) } else { None },
+            min_ledger: self.ledger_range.as_ref().map(|range| *range.start()),
+            max_ledger: self.ledger_range.as_ref().map(|range| *range.end()),
+        }.serialize(serializer)
+    }
+}
+
+#[derive(Debug)]
+pub enum TxBody {
+    Json {
+        tx: TransactionJson,
+        meta: Option<TransactionMetadata>,
+    },
+    Binary {
+        tx: Vec<u8>,
+        meta: Option<Vec<u8>>,
+    },
+}
+
+#[derive(Debug)]
+pub struct TxResponse {
+    pub body: TxBody,
+    /// Absent if the transaction is not yet in a ledger.
+    pub ledger_index: Option<u32>,
+    /// Close time of the ledger, in seconds since the Ripple Epoch
+    pub date: Option<u32>,
+    pub ctid: Option<String>,
+    pub validated: bool,
+}
+
+impl<'de> Deserialize<'de> for TxResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let mut value = Map::<String, Value>::deserialize(deserializer)?;
+        let meta = value.remove("meta");
+        let ledger_index = value.remove("ledger_index").map(u32::deserialize).transpose().map_err(de::Error::custom)?;
+        let date = value.remove("date").map(u32::deserialize).transpose().map_err(de::Error::custom)?;
+        let ctid = value.remove("ctid").map(String::deserialize).transpose().map_err(de::Error::custom)?;
+        let validated = value.remove("validated").map(bool::deserialize).transpose().map_err(de::Error::custom)?;
+        value.remove("inLedger");
+        let binary_tx = match value.get("tx") {
+            Some(Value::String(tx)) => Some(hex::decode(tx).map_err(de::Error::custom)?),
+            _ => None,
+        };
+        let body = if let Some(tx) = binary_tx {
+            TxBody::Binary {
+                tx,
+                meta: match meta {
+                    Some(Value::String(meta)) => Some(hex::decode(meta).map_err(de::Error::custom)?),
+                    _ => None,
+                },
+            }
+        } else {
+            TxBody::Json {
+                tx: TransactionJson::deserialize(Value::Object(value)).map_err(de::Error::custom)?,
+                meta: meta.map(TransactionMetadata::deserialize).transpose().map_err(de::Error::custom)?,
+            }
+        };
+        Ok(TxResponse {
+            body,
+            ledger_index,
+            date,
+            ctid,
+            validated: validated == Some(true),
+        })
+    }
+}
+
+/// Whether the server has searched the whole requested ledger range,
+/// for a `txnNotFound` error.
+///
+/// If `false`, the transaction may still be in a ledger that the server does not have.
+pub fn searched_all(error: &XrplStatusError) -> Option<bool> {
+    error.result.as_ref()?.get("searched_all")?.as_bool()
+}
+
+/// If the transaction is not found, the error is `XrplError::XrplStatus` with code `txnNotFound`
+/// (see `searched_all`).
+pub async fn tx<'a, A>(api: &'a A, data: &'a TxRequest)
+    -> Result<TypedResponse<TxResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "tx",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use crate::methods::tx::{encode_ctid, TxBody, TxResponse};
+
+    #[test]
+    fn test_ctid() {
+        assert_eq!(encode_ctid(1, 2, 3), Some(0xC000000100020003));
+        assert_eq!(format!("{:016X}", encode_ctid(0x0FFFFFFF, 0xFFFF, 0xFFFF).unwrap()), "CFFFFFFFFFFFFFFF");
+        assert_eq!(encode_ctid(0x10000000, 0, 0), None);
+    }
+
+    #[test]
+    fn test_deserialize_response() {
+        let response: TxResponse = serde_json::from_value(json!({
+            "Account": "rU4Ai74ohgtUP8evP3qd2HuxWSFvLVt7uh",
+            "Amount": "1000",
+            "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Fee": "12",
+            "Flags": 0,
+            "Sequence": 5,
+            "SigningPubKey": "EDC5248F3F06990D2E694C83AF55C45206ACD4AABC1151020600ECD6B75A5FF628",
+            "TransactionType": "Payment",
+            "hash": "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7",
+            "ledger_index": 56865245,
+            "meta": {
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS",
+                "delivered_amount": "1000",
+            },
+            "validated": true,
+        })).unwrap();
+        assert_eq!(response.ledger_index, Some(56865245));
+        assert!(response.validated);
+        let TxBody::Json { tx, meta } = response.body else {
+            panic!("expected JSON transaction");
+        };
+        assert_eq!(tx.transaction_type, "Payment");
+        assert_eq!(tx.fee, 12);
+        assert!(tx.fields.contains_key("Destination"));
+        assert_eq!(meta.unwrap().transaction_result, "tesSUCCESS");
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{de, Deserialize, Serialize};
+use crate::connection::{Api, XrplError};
+use crate::methods::tx::TransactionJson;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Serialize)]
+pub struct TxHistoryRequest {
+    /// Number of the most recent transactions to skip
+    pub start: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TxHistoryResponse {
+    pub index: u32,
+    pub txs: Vec<TransactionJson>,
+}
+
+/// Most recent transactions (deprecated by the server, may be removed in future).
+pub async fn tx_history<'a, A>(api: &'a A, data: &'a TxHistoryRequest)
+    -> Result<TypedResponse<TxHistoryResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = TypedRequest {
+        command: "tx_history",
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(de::Error::custom)?).await?.try_into()?)
+}
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorSite {
+    /// Such as `"accepted"` or `"invalid"`
+    pub last_refresh_status: Option<String>,
+    pub last_refresh_message: Option<String>,
+    /// Human-readable time
+    pub last_refresh_time: Option<String>,
+    pub next_refresh_time: Option<String>,
+    /// In minutes
+    pub refresh_interval_min: u32,
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorListSitesResponse {
+    pub validator_sites: Vec<ValidatorSite>,
+}
+
+/// Status of the sites serving validator lists (admin only)
+pub async fn validator_list_sites<A>(api: &AdminApi<'_, A>)
+    -> Result<TypedResponse<ValidatorListSitesResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "validator_list_sites",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::admin::AdminApi;
+use crate::connection::{Api, XrplError};
+use crate::request::Request;
+use crate::response::TypedResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct PublisherList {
+    pub available: bool,
+    /// Human-readable time
+    pub expiration: Option<String>,
+    /// Validator master public keys (base58)
+    #[serde(default)]
+    pub list: Vec<String>,
+    pub pubkey_publisher: String,
+    pub seq: Option<u32>,
+    pub uri: Option<String>,
+    pub version: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorListSummary {
+    pub count: u32,
+    /// Human-readable time, or `"never"` / `"unknown"`
+    pub expiration: String,
+    pub status: Option<String>,
+    pub validator_list_threshold: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorsResponse {
+    /// Validators configured in the config file
+    #[serde(default)]
+    pub local_static_keys: Vec<String>,
+    #[serde(default)]
+    pub publisher_lists: Vec<PublisherList>,
+    /// Ephemeral signing keys by master key
+    #[serde(default)]
+    pub signing_keys: HashMap<String, String>,
+    #[serde(default)]
+    pub trusted_validator_keys: Vec<String>,
+    pub validation_quorum: u32,
+    pub validator_list: Option<ValidatorListSummary>,
+}
+
+/// Validators and validator lists used by the server (admin only)
+pub async fn validators<A>(api: &AdminApi<'_, A>) -> Result<TypedResponse<ValidatorsResponse>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>
+{
+    let request = Request {
+        command: "validators",
+        api_version: Some(1),
+        params: Value::Object(serde_json::Map::new()),
+    };
+    Ok(api.api().call(request).await?.try_into()?)
+}
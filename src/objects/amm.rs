@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use crate::hashes::Address;
+use crate::objects::amount::{Amount, Issue};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuthAccount {
+    #[serde(rename = "Account")]
+    pub account: Address,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuctionSlot {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    /// Additional accounts that can trade at the discounted fee
+    #[serde(rename = "AuthAccounts")]
+    #[serde(default, deserialize_with = "crate::types::wrapped::deserialize")]
+    pub auth_accounts: Vec<AuthAccount>,
+    /// Omitted if zero
+    #[serde(rename = "DiscountedFee")]
+    #[serde(default)]
+    pub discounted_fee: u16,
+    /// Seconds since the Ripple Epoch
+    #[serde(rename = "Expiration")]
+    pub expiration: u32,
+    /// LP tokens paid for the slot
+    #[serde(rename = "Price")]
+    pub price: Amount,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoteEntry {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    /// In units of 1/100000
+    #[serde(rename = "TradingFee")]
+    #[serde(default)]
+    pub trading_fee: u16,
+    #[serde(rename = "VoteWeight")]
+    pub vote_weight: u32,
+}
+
+/// Automated market maker (liquidity pool)
+///
+/// The pool balances are the balances of `account`, not fields of this object.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AMM {
+    /// Special account holding the pool assets
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "Asset")]
+    pub asset: Issue,
+    #[serde(rename = "Asset2")]
+    pub asset2: Issue,
+    #[serde(rename = "AuctionSlot")]
+    pub auction_slot: Option<AuctionSlot>,
+    #[serde(rename = "LPTokenBalance")]
+    pub lp_token_balance: Amount,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    /// In units of 1/100000, omitted if zero
+    #[serde(rename = "TradingFee")]
+    #[serde(default)]
+    pub trading_fee: u16,
+    #[serde(rename = "VoteSlots")]
+    #[serde(default, deserialize_with = "crate::types::wrapped::deserialize")]
+    pub vote_slots: Vec<VoteEntry>,
+}
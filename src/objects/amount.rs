@@ -1,8 +1,118 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 use crate::hashes::Address;
 
-#[derive(Clone)]
+/// Token (issued currency) amount
+#[derive(Clone, Debug)]
 pub struct Amount {
     pub value: f64,
     pub currency: String,
     pub issuer: Address,
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Serialize)]
+struct Amount2 {
+    #[serde(with = "crate::types::token")]
+    pub value: f64,
+    pub currency: String,
+    pub issuer: Address,
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let value: Amount2 = Amount2::deserialize(deserializer)?;
+        Ok(Amount {
+            value: value.value,
+            currency: value.currency,
+            issuer: value.issuer,
+        })
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        Amount2 {
+            value: self.value,
+            currency: self.currency.clone(),
+            issuer: self.issuer.clone(),
+        }.serialize(serializer)
+    }
+}
+
+/// Either XRP (in drops) or token amount
+///
+/// In JSON XRP is represented as a string of drops and a token as an object.
+#[derive(Clone, Debug)]
+pub enum AnyAmount {
+    Xrp(u64),
+    Token(Amount),
+}
+
+impl<'de> Deserialize<'de> for AnyAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        match Value::deserialize(deserializer)? {
+            Value::String(s) => Ok(AnyAmount::Xrp(
+                crate::types::decode_xrp_amount(&s).map_err(de::Error::custom)?
+            )),
+            value @ Value::Object(_) => Ok(AnyAmount::Token(
+                Amount::deserialize(value).map_err(de::Error::custom)?
+            )),
+            _ => Err(de::Error::custom("expected XRP or token amount")),
+        }
+    }
+}
+
+impl Serialize for AnyAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        match self {
+            AnyAmount::Xrp(drops) => crate::types::xrp::serialize(drops, serializer),
+            AnyAmount::Token(amount) => amount.serialize(serializer),
+        }
+    }
+}
+
+/// Currency without amount: XRP or token (currency and issuer)
+#[derive(Clone, Debug)]
+pub enum Issue {
+    Xrp,
+    Token {
+        currency: String,
+        issuer: Address,
+    },
+}
+
+#[derive(Deserialize, Serialize)]
+struct Issue2 {
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<Address>,
+}
+
+impl<'de> Deserialize<'de> for Issue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let value: Issue2 = Issue2::deserialize(deserializer)?;
+        match value.issuer {
+            None if value.currency == "XRP" => Ok(Issue::Xrp),
+            Some(issuer) => Ok(Issue::Token {
+                currency: value.currency,
+                issuer,
+            }),
+            None => Err(de::Error::missing_field("issuer")),
+        }
+    }
+}
+
+impl Serialize for Issue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        match self {
+            Issue::Xrp => Issue2 {
+                currency: "XRP".to_owned(),
+                issuer: None,
+            },
+            Issue::Token { currency, issuer } => Issue2 {
+                currency: currency.clone(),
+                issuer: Some(issuer.clone()),
+            },
+        }.serialize(serializer)
+    }
+}
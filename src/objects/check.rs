@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use sha2::{Digest, Sha512};
+use crate::hashes::Address;
+use crate::objects::amount::AnyAmount;
+use crate::types::Hash;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Check {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "Destination")]
+    pub destination: Address,
+    #[serde(rename = "DestinationNode")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub destination_node: Option<u64>,
+    #[serde(rename = "DestinationTag")]
+    pub destination_tag: Option<u32>,
+    #[serde(rename = "Expiration")]
+    pub expiration: Option<u32>,
+    #[serde(rename = "Flags")]
+    pub flags: u32,
+    #[serde(rename = "InvoiceID")]
+    pub invoice_id: Option<Hash<32>>,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+    #[serde(rename = "SendMax")]
+    pub send_max: AnyAmount,
+    #[serde(rename = "Sequence")]
+    pub sequence: u32,
+    #[serde(rename = "SourceTag")]
+    pub source_tag: Option<u32>,
+}
+
+/// Ledger object ID (index) of the check created by `account` with sequence (or ticket) `sequence`
+pub fn check_id(account: &Address, sequence: u32) -> Hash<32> {
+    let mut hasher = Sha512::new();
+    hasher.update(b"\0C"); // ledger space of checks
+    hasher.update(account.0.0);
+    hasher.update(sequence.to_be_bytes());
+    Hash(hasher.finalize()[..32].try_into().unwrap()) // SHA-512Half
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hashes::Address;
+    use crate::objects::check::check_id;
+
+    #[test]
+    fn test_check_id() {
+        // The check created by `CheckCreate` 5463C6E08862A1FAE5EDAC12D70ADB16546A1F674930521295BC082494B62924
+        // with `Sequence` 2 has this index.
+        let account = Address::decode("rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo").unwrap();
+        assert_eq!(
+            hex::encode_upper(check_id(&account, 2).0),
+            "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0",
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use crate::hashes::Address;
+use crate::types::Hash;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DepositPreauth {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "Authorize")]
+    pub authorize: Address,
+    #[serde(rename = "Flags")]
+    pub flags: u32,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+}
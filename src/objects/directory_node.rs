@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use crate::hashes::Address;
+use crate::types::Hash;
+
+/// Owner directory or order book directory page
+#[derive(Clone, Debug, Deserialize)]
+pub struct DirectoryNode {
+    #[serde(rename = "ExchangeRate")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub exchange_rate: Option<u64>,
+    #[serde(rename = "Flags")]
+    pub flags: u32,
+    #[serde(rename = "Indexes")]
+    pub indexes: Vec<Hash<32>>,
+    #[serde(rename = "IndexNext")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub index_next: Option<u64>,
+    #[serde(rename = "IndexPrevious")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub index_previous: Option<u64>,
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Option<Hash<32>>,
+    #[serde(rename = "Owner")]
+    pub owner: Option<Address>,
+    #[serde(rename = "RootIndex")]
+    pub root_index: Hash<32>,
+    #[serde(rename = "TakerGetsCurrency")]
+    pub taker_gets_currency: Option<Hash<20>>,
+    #[serde(rename = "TakerGetsIssuer")]
+    pub taker_gets_issuer: Option<Hash<20>>,
+    #[serde(rename = "TakerPaysCurrency")]
+    pub taker_pays_currency: Option<Hash<20>>,
+    #[serde(rename = "TakerPaysIssuer")]
+    pub taker_pays_issuer: Option<Hash<20>>,
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use crate::hashes::Address;
+use crate::types::Hash;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Escrow {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "Amount")]
+    #[serde(with = "crate::types::xrp")]
+    pub amount: u64,
+    #[serde(rename = "CancelAfter")]
+    pub cancel_after: Option<u32>,
+    #[serde(rename = "Condition")]
+    #[serde(default, with = "crate::types::option_blob")]
+    pub condition: Option<Vec<u8>>,
+    #[serde(rename = "Destination")]
+    pub destination: Address,
+    #[serde(rename = "DestinationNode")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub destination_node: Option<u64>,
+    #[serde(rename = "DestinationTag")]
+    pub destination_tag: Option<u32>,
+    #[serde(rename = "FinishAfter")]
+    pub finish_after: Option<u32>,
+    #[serde(rename = "Flags")]
+    pub flags: u32,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+    #[serde(rename = "SourceTag")]
+    pub source_tag: Option<u32>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use crate::objects::account_root::AccountRoot;
+use crate::objects::amm::AMM;
+use crate::objects::check::Check;
+use crate::objects::deposit_preauth::DepositPreauth;
+use crate::objects::directory_node::DirectoryNode;
+use crate::objects::escrow::Escrow;
+use crate::objects::nftoken_offer::NFTokenOffer;
+use crate::objects::nftoken_page::NFTokenPage;
+use crate::objects::offer::Offer;
+use crate::objects::pay_channel::PayChannel;
+use crate::objects::ripple_state::RippleState;
+use crate::objects::signer_list::SignerList;
+use crate::objects::ticket::Ticket;
+
+/// Any ledger object, distinguished by its `LedgerEntryType` field
+#[derive(Debug, Deserialize)]
+#[serde(tag = "LedgerEntryType")]
+pub enum LedgerEntry {
+    AccountRoot(AccountRoot),
+    AMM(AMM),
+    Check(Check),
+    DepositPreauth(DepositPreauth),
+    DirectoryNode(DirectoryNode),
+    Escrow(Escrow),
+    NFTokenOffer(NFTokenOffer),
+    NFTokenPage(NFTokenPage),
+    Offer(Offer),
+    PayChannel(PayChannel),
+    RippleState(RippleState),
+    SignerList(SignerList),
+    Ticket(Ticket),
+    /// A ledger object type not (yet) supported by this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Ledger object type for filtering in requests (the `type` field)
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryType {
+    Amm,
+    Check,
+    DepositPreauth,
+    Directory,
+    Escrow,
+    NftOffer,
+    NftPage,
+    Offer,
+    PaymentChannel,
+    SignerList,
+    State,
+    Ticket,
+}
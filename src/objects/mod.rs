@@ -1,3 +1,15 @@
 pub mod amount;
 pub mod account_root;
-pub mod signer_list;
\ No newline at end of file
+pub mod amm;
+pub mod check;
+pub mod deposit_preauth;
+pub mod directory_node;
+pub mod escrow;
+pub mod ledger_entry;
+pub mod nftoken_offer;
+pub mod nftoken_page;
+pub mod offer;
+pub mod pay_channel;
+pub mod ripple_state;
+pub mod signer_list;
+pub mod ticket;
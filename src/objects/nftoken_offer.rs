@@ -0,0 +1,43 @@
+use serde::{Deserialize, Deserializer};
+use crate::hashes::Address;
+use crate::objects::amount::AnyAmount;
+use crate::types::Hash;
+
+#[derive(Clone, Copy, Debug)]
+pub struct NFTokenOfferFlags(pub u32);
+
+pub mod nftoken_offer_flags {
+    pub const LSF_SELL_NFTOKEN: u32 = 0x00000001;
+}
+
+impl<'de> Deserialize<'de> for NFTokenOfferFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        Ok(NFTokenOfferFlags(u32::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NFTokenOffer {
+    #[serde(rename = "Amount")]
+    pub amount: AnyAmount,
+    #[serde(rename = "Destination")]
+    pub destination: Option<Address>,
+    #[serde(rename = "Expiration")]
+    pub expiration: Option<u32>,
+    #[serde(rename = "Flags")]
+    pub flags: NFTokenOfferFlags,
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Hash<32>,
+    #[serde(rename = "NFTokenOfferNode")]
+    #[serde(default, with = "crate::types::option_uint64")]
+    pub nftoken_offer_node: Option<u64>,
+    #[serde(rename = "Owner")]
+    pub owner: Address,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use crate::types::Hash;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NFToken {
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: Hash<32>,
+    #[serde(rename = "URI")]
+    #[serde(default, with = "crate::types::option_blob")]
+    pub uri: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NFTokenPage {
+    #[serde(rename = "NextPageMin")]
+    pub next_page_min: Option<Hash<32>>,
+    #[serde(rename = "NFTokens")]
+    #[serde(deserialize_with = "crate::types::wrapped::deserialize")]
+    pub nftokens: Vec<NFToken>,
+    #[serde(rename = "PreviousPageMin")]
+    pub previous_page_min: Option<Hash<32>>,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<Hash<32>>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: Option<u32>,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Deserializer};
+use crate::hashes::Address;
+use crate::objects::amount::AnyAmount;
+use crate::types::Hash;
+
+#[derive(Clone, Copy, Debug)]
+pub struct OfferFlags(pub u32);
+
+pub mod offer_flags {
+    pub const LSF_PASSIVE: u32 = 0x00010000;
+    pub const LSF_SELL: u32 = 0x00020000;
+}
+
+impl<'de> Deserialize<'de> for OfferFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        Ok(OfferFlags(u32::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Offer {
+    #[serde(rename = "Account")]
+    pub account: Address,
+    #[serde(rename = "BookDirectory")]
+    pub book_directory: Hash<32>,
+    #[serde(rename = "BookNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub book_node: u64,
+    #[serde(rename = "Expiration")]
+    pub expiration: Option<u32>,
+    #[serde(rename = "Flags")]
+    pub flags: OfferFlags,
+    #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub owner_node: u64,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+    #[serde(rename = "Sequence")]
+    pub sequence: u32,
+    #[serde(rename = "TakerGets")]
+    pub taker_gets: AnyAmount,
+    #[serde(rename = "TakerPays")]
+    pub taker_pays: AnyAmount,
+}
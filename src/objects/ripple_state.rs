@@ -0,0 +1,58 @@
+use serde::{Deserialize, Deserializer};
+use crate::objects::amount::Amount;
+use crate::types::Hash;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RippleStateFlags(pub u32);
+
+pub mod ripple_state_flags {
+    pub const LSF_AMM_NODE: u32 = 0x01000000;
+    pub const LSF_LOW_RESERVE: u32 = 0x00010000;
+    pub const LSF_HIGH_RESERVE: u32 = 0x00020000;
+    pub const LSF_LOW_AUTH: u32 = 0x00040000;
+    pub const LSF_HIGH_AUTH: u32 = 0x00080000;
+    pub const LSF_LOW_NO_RIPPLE: u32 = 0x00100000;
+    pub const LSF_HIGH_NO_RIPPLE: u32 = 0x00200000;
+    pub const LSF_LOW_FREEZE: u32 = 0x00400000;
+    pub const LSF_HIGH_FREEZE: u32 = 0x00800000;
+    pub const LSF_LOW_DEEP_FREEZE: u32 = 0x02000000;
+    pub const LSF_HIGH_DEEP_FREEZE: u32 = 0x04000000;
+}
+
+impl<'de> Deserialize<'de> for RippleStateFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        Ok(RippleStateFlags(u32::deserialize(deserializer)?))
+    }
+}
+
+/// Trust line
+#[derive(Clone, Debug, Deserialize)]
+pub struct RippleState {
+    /// Issuer in this field is always the special "no account" address.
+    #[serde(rename = "Balance")]
+    pub balance: Amount,
+    #[serde(rename = "Flags")]
+    pub flags: RippleStateFlags,
+    #[serde(rename = "HighLimit")]
+    pub high_limit: Amount,
+    #[serde(rename = "HighNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub high_node: u64,
+    #[serde(rename = "HighQualityIn")]
+    pub high_quality_in: Option<u32>,
+    #[serde(rename = "HighQualityOut")]
+    pub high_quality_out: Option<u32>,
+    #[serde(rename = "LowLimit")]
+    pub low_limit: Amount,
+    #[serde(rename = "LowNode")]
+    #[serde(with = "crate::types::uint64")]
+    pub low_node: u64,
+    #[serde(rename = "LowQualityIn")]
+    pub low_quality_in: Option<u32>,
+    #[serde(rename = "LowQualityOut")]
+    pub low_quality_out: Option<u32>,
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Hash<32>,
+    #[serde(rename = "PreviousTxnLgrSeq")]
+    pub previous_txn_lgr_seq: u32,
+}
@@ -22,7 +22,7 @@ pub struct SignerEntry {
     #[serde(rename = "SignerWeight")]
     pub signer_weight: u16,
     #[serde(rename = "WalletLocator")]
-    pub wallet_locator: Hash<32>,
+    pub wallet_locator: Option<Hash<32>>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -30,15 +30,17 @@ pub struct SignerList {
     #[serde(rename = "Flags")]
     pub flags: SignerListFlags,
     #[serde(rename = "OwnerNode")]
+    #[serde(with = "crate::types::uint64")]
     pub owner_node: u64,
     #[serde(rename = "PreviousTxnID")]
     pub previous_txn_id: Hash<32>,
     #[serde(rename = "PreviousTxnLgrSeq")]
     pub previous_txn_lgr_seq: u32,
     #[serde(rename = "SignerEntries")]
+    #[serde(deserialize_with = "crate::types::wrapped::deserialize")]
     pub signer_entries: Vec<SignerEntry>,
     #[serde(rename = "SignerListID")]
     pub signer_list_id: u32,
     #[serde(rename = "SignerQuorum")]
     pub signer_quorum: u32,
-}
\ No newline at end of file
+}
@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use lazy_static::lazy_static;
@@ -20,34 +21,99 @@ pub trait PaginatorExtractor<'de>: Deserialize<'de> + Unpin {
     }
 }
 
-pub struct Paginator<'a, A: Api, T: PaginatorExtractor<'a>> where A::Error: From<XrplError> {
+type CallFuture<'a, E> = Pin<Box<dyn Future<Output = Result<Response, E>> + Send + 'a>>;
+
+/// Where the list is in a page result
+#[derive(Clone, Copy)]
+enum ListSource<'a> {
+    /// `PaginatorExtractor::list`
+    Extractor(fn(&Value) -> Result<&Vec<Value>, XrplError>),
+    /// `result[key]`, see `start_with_list_key`
+    Key(&'a str),
+}
+
+pub struct Paginator<'a, A: Api, T: Deserialize<'a> + Unpin> where A::Error: From<XrplError> {
     api: &'a A,
     request: Request<'a>,
     list: VecDeque<T>, // more efficient than `Vec`
     marker: Option<Value>,
+    list_source: ListSource<'a>,
+    /// The request for the next page, kept across polls while it is in flight.
+    next_page: Option<CallFuture<'a, A::Error>>,
+    // Of the response of the current page:
+    load: bool,
+    warnings: Option<Vec<Warning>>,
+    forwarded: bool,
 }
 
-impl<'a, A: Api, T: PaginatorExtractor<'a>> Paginator<'a, A, T>
+fn list_by_key<'r>(result: &'r Value, key: &str) -> Result<&'r Vec<Value>, XrplError> {
+    result.get(key)
+        .ok_or_else::<XrplError, _>(|| de::Error::custom(format!("missing field `{}`", key)))?
+        .as_array().ok_or::<XrplError>(de::Error::custom("expected array"))
+}
+
+impl<'a, A: Api, T: Deserialize<'a> + Unpin> Paginator<'a, A, T>
     where A::Error: From<XrplError>
 {
-    fn new(api: &'a A, request: Request<'a>, first_page_list: VecDeque<T>) -> Self {
+    fn new(api: &'a A, request: Request<'a>, list_source: ListSource<'a>) -> Self {
         Self {
             api,
             request,
-            list: first_page_list,
+            list: VecDeque::new(),
             marker: None,
+            list_source,
+            next_page: None,
+            load: false,
+            warnings: None,
+            forwarded: false,
         }
     }
-    pub async fn start(api: &'a A, request: Request<'a>) -> Result<(Response, Paginator<'a, A, T>), A::Error> {
-        let response = api.call(request.clone()).await?;
-        let list = T::list(&response.result)
-            .map_err(de::Error::custom)?
+    fn list(list_source: ListSource, result: &Value) -> Result<VecDeque<T>, XrplError> {
+        match list_source {
+            ListSource::Extractor(list) => list(result),
+            ListSource::Key(key) => list_by_key(result, key),
+        }?
             .iter()
             .map(|e| T::deserialize(e.clone()).map_err(de::Error::custom))
-            .collect::<Result<VecDeque<T>, XrplError>>().map_err::<XrplError, _>(de::Error::custom)?;
-        Ok((response, Self::new(api, request, list)))
+            .collect::<Result<VecDeque<T>, XrplError>>()
+    }
+    /// Take the list and the marker of the next page from a page response.
+    fn set_page(&mut self, response: &Response) -> Result<(), XrplError> {
+        self.list = Self::list(self.list_source, &response.result)?;
+        self.marker = response.result.get(&*MARKER_KEY).cloned();
+        self.load = response.load;
+        self.warnings = response.warnings.clone();
+        self.forwarded = response.forwarded;
+        Ok(())
     }
-    pub async fn first_page(api: &'a A, request: Request<'a>) -> Result<(Response, Vec<T>), A::Error> {
+    async fn start_from(api: &'a A, request: Request<'a>, list_source: ListSource<'a>)
+        -> Result<(Response, Paginator<'a, A, T>), A::Error>
+    {
+        let response = api.call(request.clone()).await?;
+        let mut paginator = Self::new(api, request, list_source);
+        paginator.set_page(&response)?;
+        Ok((response, paginator))
+    }
+    pub async fn start(api: &'a A, request: Request<'a>) -> Result<(Response, Paginator<'a, A, T>), A::Error>
+        where T: PaginatorExtractor<'a>
+    {
+        Self::start_from(api, request, ListSource::Extractor(T::list)).await
+    }
+    /// Like `start`, but the list is taken from `result[list_key]`, for `T` without `PaginatorExtractor`.
+    pub async fn start_with_list_key(api: &'a A, request: Request<'a>, list_key: &'a str)
+        -> Result<(Response, Paginator<'a, A, T>), A::Error>
+    {
+        Self::start_from(api, request, ListSource::Key(list_key)).await
+    }
+    /// Change a parameter of the requests for the subsequent pages.
+    pub(crate) fn set_param(&mut self, key: &str, value: Value) {
+        if let Value::Object(obj) = &mut self.request.params {
+            obj.insert(key.to_owned(), value);
+        }
+    }
+    pub async fn first_page(api: &'a A, request: Request<'a>) -> Result<(Response, Vec<T>), A::Error>
+        where T: PaginatorExtractor<'a>
+    {
         let response = api.call(request.clone()).await?;
         let list: Vec<T> = T::list(&response.result)
             .map_err(de::Error::custom)?
@@ -58,7 +124,7 @@ impl<'a, A: Api, T: PaginatorExtractor<'a>> Paginator<'a, A, T>
     }
 }
 
-impl<'a, A: Api, T: PaginatorExtractor<'a>> Stream for Paginator<'a, A, T>
+impl<'a, A: Api, T: Deserialize<'a> + Unpin> Stream for Paginator<'a, A, T>
     where A::Error: From<XrplError>
 {
     type Item = Result<TypedResponse<T>, A::Error>;
@@ -67,60 +133,131 @@ impl<'a, A: Api, T: PaginatorExtractor<'a>> Stream for Paginator<'a, A, T>
         cx: &mut Context<'_>
     ) -> Poll<Option<Self::Item>> {
         let this = self.get_mut();
-        let mut forwarded: bool = false;
-        let mut load: bool = false;
-        let mut warnings: Option<Vec<Warning>> = None;
-        if let Some(front) = this.list.pop_front() {
-            Poll::Ready(Some(Ok(TypedResponse {
-                result: front,
-                load: load && this.list.is_empty(), // for the last item in the downloaded list
-                warnings,
-                forwarded,
-            })))
-        } else {
-            let marker = this.marker.clone();
-            let mut loader = |request: &Request| {
-                match this.api.call(request.clone()).as_mut().poll(cx) { // Think, if clone can be removed here.
-                    Poll::Ready(response) => {
-                        let response = response?;
-                        load = response.load;
-                        forwarded = response.forwarded;
-                        warnings = response.warnings;
-                        // Duplicate code:
-                        // Can do without `clone`?
-                        this.list = T::list(&response.result)
-                            .map_err(de::Error::custom)?
-                            .iter()
-                            .map(|e| T::deserialize(e.clone()).map_err(de::Error::custom))
-                            .collect::<Result<VecDeque<T>, XrplError>>()?;
-                        this.marker = response.result.get(&*MARKER_KEY).cloned();
-                        if let Some(front) = this.list.pop_front() {
-                            Poll::Ready(Some(Ok(
-                                TypedResponse {
-                                    result: front,
-                                    load: load && this.list.is_empty(), // for the last item in the downloaded list
-                                    warnings: warnings.clone(), // TODO: `clone` is against "value captured by `warnings` is never read" in Clippy (apparently, a compiler bug)
-                                    forwarded,
-                                }
-                            )))
-                        } else {
-                            Poll::Ready(None)
-                        }
-                    },
-                    Poll::Pending => Poll::Pending,
-                }
+        loop {
+            if let Some(front) = this.list.pop_front() {
+                return Poll::Ready(Some(Ok(TypedResponse {
+                    result: front,
+                    load: this.load && this.list.is_empty(), // for the last item in the downloaded list
+                    warnings: this.warnings.clone(),
+                    forwarded: this.forwarded,
+                })));
+            }
+            if this.next_page.is_none() {
+                let Some(marker) = this.marker.take() else {
+                    return Poll::Ready(None);
+                };
+                this.set_param(&MARKER_KEY, marker);
+                let api = this.api;
+                this.next_page = Some(api.call(this.request.clone()));
+            }
+            let response = match this.next_page.as_mut().unwrap().as_mut().poll(cx) {
+                Poll::Ready(response) => response,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.next_page = None;
+            let result = response.and_then(|response| Ok(this.set_page(&response)?));
+            if let Err(err) = result {
+                return Poll::Ready(Some(Err(err)));
+            }
+            // A page may be empty but still have a marker: then load the next one.
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Mutex;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    use async_trait::async_trait;
+    use serde::{de, Deserialize};
+    use serde_json::{json, Value};
+    use tokio_stream::Stream;
+    use crate::connection::{Api, XrplError};
+    use crate::paginate::{Paginator, PaginatorExtractor};
+    use crate::request::Request;
+    use crate::response::Response;
+
+    /// Returns two pages, the first one with `marker: "m1"`.
+    struct MockApi {
+        markers: Mutex<Vec<Option<Value>>>,
+    }
+
+    #[async_trait]
+    impl Api for MockApi {
+        type Error = XrplError;
+        async fn call<'a>(&self, request: Request<'a>) -> Result<Response, XrplError> {
+            let marker = request.params.get("marker").cloned();
+            self.markers.lock().unwrap().push(marker.clone());
+            let result = match marker {
+                None => json!({"items": [1, 2], "marker": "m1"}),
+                Some(_) => json!({"items": [3]}),
             };
-            if let Some(marker) = marker {
-                let mut request = &mut this.request;
-                if let Value::Object(obj) = &request.params {
-                    let mut m = obj.clone();
-                    m.insert(MARKER_KEY.clone(), marker);
-                    request.params = Value::Object(m);
-                }
-                loader(request)
-            } else {
-                Poll::Ready(None)
+            Ok(Response { result, load: false, warnings: None, forwarded: false })
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item(u32);
+
+    impl PaginatorExtractor<'_> for Item {
+        fn list_obj(result: &Value) -> Result<&Value, XrplError> {
+            result.get("items").ok_or(de::Error::custom("missing field `items`"))
+        }
+    }
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+
+    /// The mock API never returns `Pending`, so a single poll suffices.
+    fn poll_ready<F: Future>(future: F) -> F::Output {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("unexpected pending"),
+        }
+    }
+
+    #[test]
+    fn test_two_pages() {
+        let api = MockApi { markers: Mutex::new(Vec::new()) };
+        let request = Request { command: "items", api_version: None, params: json!({}) };
+        let (_, mut paginator) = poll_ready(Paginator::<_, Item>::start(&api, request)).unwrap();
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut items = Vec::new();
+        loop {
+            match Pin::new(&mut paginator).poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => items.push(item.unwrap().result),
+                Poll::Ready(None) => break,
+                Poll::Pending => panic!("unexpected pending"),
             }
         }
+        assert_eq!(items, vec![Item(1), Item(2), Item(3)]);
+        assert_eq!(*api.markers.lock().unwrap(), vec![None, Some(json!("m1"))]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_two_pages_with_list_key() {
+        let api = MockApi { markers: Mutex::new(Vec::new()) };
+        let request = Request { command: "items", api_version: None, params: json!({}) };
+        let (_, mut paginator) =
+            poll_ready(Paginator::<_, u32>::start_with_list_key(&api, request, "items")).unwrap();
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = Pin::new(&mut paginator).poll_next(&mut cx) {
+            items.push(item.unwrap().result);
+        }
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+}
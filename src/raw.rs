@@ -0,0 +1,62 @@
+//! Escape hatch for commands not (yet) supported by this crate
+//!
+//! ```ignore
+//! #[derive(Serialize)]
+//! struct NewCommandRequest { account: Address }
+//! #[derive(Deserialize)]
+//! struct NewCommandResponse { count: u32 }
+//!
+//! let response: TypedResponse<NewCommandResponse> =
+//!     raw_call(&api, "new_command", &NewCommandRequest { account }).await?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use crate::connection::{Api, XrplError};
+use crate::paginate::Paginator;
+use crate::request::TypedRequest;
+use crate::response::TypedResponse;
+
+/// Call `command` with the fields of `data` as parameters and deserialize the result as `Resp`.
+pub async fn raw_call<'a, A, Req, Resp>(api: &'a A, command: &'a str, data: &'a Req)
+    -> Result<TypedResponse<Resp>, A::Error>
+    where A: Api,
+          A::Error: From<XrplError>,
+          Req: Serialize,
+          Resp: DeserializeOwned
+{
+    let request = TypedRequest {
+        command,
+        api_version: Some(1),
+        data,
+    };
+    Ok(api.call((&request).try_into().map_err(|_| XrplError::CannotConstructJson)?).await?.try_into()?)
+}
+
+/// Like `raw_call`, for a command paginated by `marker`, with the list under `list_key` in the result.
+///
+/// The returned paginator yields the list elements, starting from the first page.
+pub async fn raw_call_paginated<'a, A, Req, Resp, T>(
+    api: &'a A,
+    command: &'a str,
+    data: &'a Req,
+    list_key: &'a str,
+) -> Result<(TypedResponse<Resp>, Paginator<'a, A, T>), A::Error>
+    where A: Api,
+          A::Error: From<XrplError>,
+          Req: Serialize,
+          Resp: DeserializeOwned,
+          T: Deserialize<'a> + Unpin
+{
+    let request = TypedRequest {
+        command,
+        api_version: Some(1),
+        data,
+    };
+    let (response, paginator) = Paginator::start_with_list_key(
+        api,
+        (&request).try_into().map_err(|_| XrplError::CannotConstructJson)?,
+        list_key,
+    ).await?;
+    Ok((response.try_into()?, paginator))
+}
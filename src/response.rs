@@ -63,7 +63,7 @@ impl Response {
         if data.result.get("status") != Some(&Value::String(SUCCESS_KEY.to_owned())) {
             // duplicate code
             return if let Some(Value::String(err)) = data.result.get("error") {
-                Err(XrplStatusError::new(err.clone()).into())
+                Err(XrplStatusError::with_result(err.clone(), data.result.clone()).into())
             } else {
                 Err(XrplError::WrongFormat)
             };
@@ -117,7 +117,7 @@ impl StreamedResponse {
             (true, Ok(data)) => data,
             _ => {
                 return if let Some(Value::String(e)) = s.get(&*ERROR_KEY) {
-                    Err(XrplStatusError::new(e.clone()).into())
+                    Err(XrplStatusError::with_result(e.clone(), s.clone()).into())
                 } else {
                     Err(XrplError::WrongFormat)
                 }
@@ -3,7 +3,7 @@ use std::io::ErrorKind::InvalidData;
 use byteorder::{BigEndian, WriteBytesExt};
 use xrpl::utils::{MAX_IOU_EXPONENT, MIN_IOU_EXPONENT};
 use crate::hashes::{Address, Encoding};
-use crate::objects::amount::Amount;
+use crate::objects::amount::{Amount, AnyAmount, Issue};
 use crate::types::Hash;
 
 // in conflict with XrplBinaryField:
@@ -19,10 +19,40 @@ pub trait Serialize {
     fn serialize(&self, writer: &mut dyn Write) -> io::Result<()>;
 }
 
+/// Ends an inner object (`STObject`)
+pub const OBJECT_END_MARKER: u8 = 0xE1;
+/// Ends an array (`STArray`)
+pub const ARRAY_END_MARKER: u8 = 0xF1;
+
 pub struct XrplType {
     pub type_code: i16,
 }
 
+pub const TYPE_UINT16: XrplType = XrplType { type_code: 1 };
+pub const TYPE_HASH256: XrplType = XrplType { type_code: 5 };
+pub const TYPE_BLOB: XrplType = XrplType { type_code: 7 };
+pub const TYPE_ACCOUNT_ID: XrplType = XrplType { type_code: 8 };
+/// Type of inner objects, such as elements of arrays
+pub const TYPE_STOBJECT: XrplType = XrplType { type_code: 14 };
+
+// Field codes (`nth` in definitions.json) of the fields of inner objects, by type.
+/// `Account` (`AccountID`)
+pub const FIELD_ACCOUNT: i16 = 1;
+/// `SignerWeight` (`UInt16`)
+pub const FIELD_SIGNER_WEIGHT: i16 = 3;
+/// `WalletLocator` (`Hash256`)
+pub const FIELD_WALLET_LOCATOR: i16 = 7;
+/// `SigningPubKey` (`Blob`)
+pub const FIELD_SIGNING_PUB_KEY: i16 = 3;
+/// `TxnSignature` (`Blob`)
+pub const FIELD_TXN_SIGNATURE: i16 = 4;
+/// `SignerEntry` (`STObject`)
+pub const FIELD_SIGNER_ENTRY: i16 = 11;
+/// `Signer` (`STObject`)
+pub const FIELD_SIGNER: i16 = 16;
+/// `AuthAccount` (`STObject`)
+pub const FIELD_AUTH_ACCOUNT: i16 = 27;
+
 pub struct XrplBinaryField<'a, T> {
     pub xrpl_type: &'a XrplType,
     pub field_code: i16,
@@ -104,12 +134,47 @@ impl<'a, T> Serialize for XrplBinaryField<'a, Option<T>>
     }
 }
 
+/// `STArray` of inner objects (such as `SignerEntry` objects in `SignerEntries`), without its own field ID
+///
+/// Each element is written as the field `object_type`/`object_field_code`
+/// with the fields written by `serialize_fields`.
+pub fn serialize_array<T>(
+    writer: &mut dyn Write,
+    elements: &[T],
+    object_type: &XrplType,
+    object_field_code: i16,
+    serialize_fields: impl Fn(&T, &mut dyn Write) -> io::Result<()>,
+) -> io::Result<()> {
+    for element in elements {
+        XrplBinaryField {
+            xrpl_type: object_type,
+            field_code: object_field_code,
+            value: element,
+        }.serialize_field_uid(writer)?;
+        serialize_fields(element, writer)?;
+        writer.write_all(&[OBJECT_END_MARKER])?;
+    }
+    writer.write_all(&[ARRAY_END_MARKER])
+}
+
 impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, i16> {
     fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
         writer.write_i16::<BigEndian>(*self.0)
     }
 }
 
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, u8> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u8(*self.0)
+    }
+}
+
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, u16> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u16::<BigEndian>(*self.0)
+    }
+}
+
 impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, u32> {
     fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
         writer.write_u32::<BigEndian>(*self.0)
@@ -182,6 +247,16 @@ impl<'a> Serialize for BinaryFormatWithoutLength<'a, Vec<u8>> {
     }
 }
 
+/// `Vector256`
+impl<'a> Serialize for BinaryFormatWithoutLength<'a, Vec<Hash<32>>> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        for hash in self.0 {
+            writer.write_all(&hash.0)?;
+        }
+        Ok(())
+    }
+}
+
 const MIN_MANTISSA: i64 = 10i64.pow(15);
 const MAX_MANTISSA: i64 = 10i64.pow(16) - 1;
 
@@ -259,6 +334,29 @@ impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, Amount> {
     }
 }
 
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, AnyAmount> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        match self.0 {
+            // bit 63: 0 = XRP, bit 62: 1 = positive
+            AnyAmount::Xrp(drops) => writer.write_u64::<BigEndian>(0x4000000000000000 | drops),
+            AnyAmount::Token(amount) => BinaryFormatWithoutFieldUid(amount).serialize(writer),
+        }
+    }
+}
+
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, Issue> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        match self.0 {
+            // XRP is only the all-zero currency, without issuer.
+            Issue::Xrp => writer.write_all(&[0; 20]),
+            Issue::Token { currency, issuer } => {
+                write_currency(writer, currency)?;
+                writer.write_all(&issuer.0.0)
+            },
+        }
+    }
+}
+
 struct Blob(Vec<u8>);
 
 impl<'a> Serialize for BinaryFormatWithoutLength<'a, Blob> {
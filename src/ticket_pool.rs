@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio_stream::StreamExt;
+use crate::connection::{Api, XrplError};
+use crate::hashes::Address;
+use crate::methods::account_objects::{account_objects, AccountObjectsRequest};
+use crate::objects::ledger_entry::{LedgerEntry, LedgerEntryType};
+use crate::types::LedgerForRequest;
+
+/// Tickets of an account, handed out to transactions
+///
+/// To use a ticket from `take`, set `sequence: Some(0)` and `ticket_sequence: Some(ticket)` on the transaction
+/// (before signing). Transactions with different tickets do not depend on each other
+/// and can be submitted in parallel.
+pub struct TicketPool {
+    account: Address,
+    tickets: Mutex<VecDeque<u32>>,
+}
+
+impl TicketPool {
+    /// Pool of the tickets of `account` in the validated ledger
+    pub async fn fetch<A>(api: &A, account: &Address) -> Result<TicketPool, A::Error>
+        where A: Api,
+              A::Error: From<XrplError>
+    {
+        let pool = Self {
+            account: account.clone(),
+            tickets: Mutex::new(VecDeque::new()),
+        };
+        pool.refresh(api).await?;
+        Ok(pool)
+    }
+    pub fn account(&self) -> &Address {
+        &self.account
+    }
+    /// Replace the pool contents by the tickets currently in the validated ledger.
+    ///
+    /// Tickets taken but not yet used would be handed out again, so call it only when none are in flight.
+    pub async fn refresh<A>(&self, api: &A) -> Result<(), A::Error>
+        where A: Api,
+              A::Error: From<XrplError>
+    {
+        let request = AccountObjectsRequest {
+            account: self.account.clone(),
+            ledger: LedgerForRequest::Validated,
+            r#type: Some(LedgerEntryType::Ticket),
+            deletion_blockers_only: None,
+            limit: None,
+        };
+        let (_, mut paginator) = account_objects(api, &request).await?;
+        let mut tickets = Vec::new();
+        while let Some(entry) = paginator.next().await {
+            if let LedgerEntry::Ticket(ticket) = entry?.result {
+                tickets.push(ticket.ticket_sequence);
+            }
+        }
+        tickets.sort_unstable();
+        *self.tickets.lock().unwrap() = tickets.into();
+        Ok(())
+    }
+    /// Take the lowest available ticket sequence, `None` if the pool is empty.
+    pub fn take(&self) -> Option<u32> {
+        self.tickets.lock().unwrap().pop_front()
+    }
+    /// Return a ticket not consumed (the transaction failed without reaching a ledger).
+    pub fn give_back(&self, ticket_sequence: u32) {
+        let mut tickets = self.tickets.lock().unwrap();
+        let pos = tickets.partition_point(|&t| t < ticket_sequence);
+        if tickets.get(pos) != Some(&ticket_sequence) {
+            tickets.insert(pos, ticket_sequence);
+        }
+    }
+    /// Add the `count` tickets created by a validated `TicketCreate`, starting from `first_ticket_sequence`.
+    ///
+    /// The first ticket is the `TicketCreate`'s `Sequence` + 1; if the `TicketCreate` itself used a ticket
+    /// (`Sequence` 0), it is the account's `Sequence` before the `TicketCreate`.
+    /// Sequences that would overflow `u32` are skipped.
+    pub fn add_created(&self, first_ticket_sequence: u32, count: u32) {
+        for ticket_sequence in (0..count).map_while(|i| first_ticket_sequence.checked_add(i)) {
+            self.give_back(ticket_sequence);
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.tickets.lock().unwrap().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use crate::hashes::Address;
+    use crate::ticket_pool::TicketPool;
+
+    fn pool(tickets: &[u32]) -> TicketPool {
+        TicketPool {
+            account: Address::decode("rU4Ai74ohgtUP8evP3qd2HuxWSFvLVt7uh").unwrap(),
+            tickets: Mutex::new(tickets.iter().copied().collect::<VecDeque<u32>>()),
+        }
+    }
+
+    #[test]
+    fn test_take() {
+        let pool = pool(&[3, 5]);
+        assert_eq!(pool.take(), Some(3));
+        assert_eq!(pool.take(), Some(5));
+        assert_eq!(pool.take(), None);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_give_back() {
+        let pool = pool(&[3, 7]);
+        pool.give_back(5);
+        pool.give_back(1);
+        pool.give_back(9);
+        // Already in the pool:
+        pool.give_back(5);
+        assert_eq!(pool.len(), 5);
+        assert_eq!((0..5).map(|_| pool.take().unwrap()).collect::<Vec<u32>>(), vec![1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_add_created() {
+        let pool = pool(&[2]);
+        // `TicketCreate` with `Sequence` 10 creates tickets 11, 12 and 13.
+        pool.add_created(11, 3);
+        assert_eq!((0..4).map(|_| pool.take().unwrap()).collect::<Vec<u32>>(), vec![2, 11, 12, 13]);
+        assert_eq!(pool.take(), None);
+        pool.add_created(u32::MAX - 1, 3);
+        assert_eq!(pool.len(), 2);
+    }
+}
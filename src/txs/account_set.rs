@@ -0,0 +1,137 @@
+use std::io::{self, Write};
+use byteorder::{BigEndian, WriteBytesExt};
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::serialize::{BinaryFormatWithoutFieldUid, Serialize};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_ACCOUNT_SET: i16 = 3;
+
+/// Account flag enabled (`SetFlag`) or disabled (`ClearFlag`) by `AccountSet`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AccountSetFlag {
+    RequireDest = 1,
+    RequireAuth = 2,
+    DisallowXrp = 3,
+    DisableMaster = 4,
+    /// Track the ID of the account's most recent transaction (`AccountTxnID`).
+    AccountTxnId = 5,
+    NoFreeze = 6,
+    GlobalFreeze = 7,
+    DefaultRipple = 8,
+    DepositAuth = 9,
+    /// Allow `NFTokenMinter` to mint on behalf of the account.
+    AuthorizedNfTokenMinter = 10,
+    DisallowIncomingNfTokenOffer = 12,
+    DisallowIncomingCheck = 13,
+    DisallowIncomingPayChan = 14,
+    DisallowIncomingTrustline = 15,
+    AllowTrustLineClawback = 16,
+}
+
+impl TryFrom<u32> for AccountSetFlag {
+    type Error = u32;
+    /// The unknown flag is the error.
+    fn try_from(value: u32) -> Result<Self, u32> {
+        Ok(match value {
+            1 => AccountSetFlag::RequireDest,
+            2 => AccountSetFlag::RequireAuth,
+            3 => AccountSetFlag::DisallowXrp,
+            4 => AccountSetFlag::DisableMaster,
+            5 => AccountSetFlag::AccountTxnId,
+            6 => AccountSetFlag::NoFreeze,
+            7 => AccountSetFlag::GlobalFreeze,
+            8 => AccountSetFlag::DefaultRipple,
+            9 => AccountSetFlag::DepositAuth,
+            10 => AccountSetFlag::AuthorizedNfTokenMinter,
+            12 => AccountSetFlag::DisallowIncomingNfTokenOffer,
+            13 => AccountSetFlag::DisallowIncomingCheck,
+            14 => AccountSetFlag::DisallowIncomingPayChan,
+            15 => AccountSetFlag::DisallowIncomingTrustline,
+            16 => AccountSetFlag::AllowTrustLineClawback,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, AccountSetFlag> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u32::<BigEndian>(*self.0 as u32)
+    }
+}
+
+#[derive(BinarySerialize, Clone, Debug)]
+pub struct AccountSetTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "SetFlag", rtype = "UInt32")]
+    pub set_flag: Option<AccountSetFlag>,
+    #[binary(id = "ClearFlag", rtype = "UInt32")]
+    pub clear_flag: Option<AccountSetFlag>,
+    /// Domain name (ASCII, lowercase); empty to remove.
+    #[binary(id = "Domain", rtype = "Blob")]
+    pub domain: Option<Vec<u8>>,
+    /// MD5 of an email address for an avatar image; zeros to remove.
+    #[binary(id = "EmailHash", rtype = "Hash128")]
+    pub email_hash: Option<Hash<16>>,
+    /// Public key for encrypted messages; empty to remove.
+    #[binary(id = "MessageKey", rtype = "Blob")]
+    pub message_key: Option<Vec<u8>>,
+    /// Fee for transfers of the account's tokens, in billionths (1000000000 is no fee, 0 to remove).
+    #[binary(id = "TransferRate", rtype = "UInt32")]
+    pub transfer_rate: Option<u32>,
+    /// Significant digits for offers of the account's tokens (3 to 15, 0 to remove).
+    #[binary(id = "TickSize", rtype = "UInt8")]
+    pub tick_size: Option<u8>,
+    /// Requires `AccountSetFlag::AuthorizedNfTokenMinter` in `set_flag`.
+    #[binary(id = "NFTokenMinter", rtype = "AccountID")]
+    pub nftoken_minter: Option<Address>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::account_set::{AccountSetFlag, AccountSetTransaction, TRANSACTION_TYPE_ACCOUNT_SET};
+    use crate::txs::test_utils::assert_serialized;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(AccountSetTransaction {
+            transaction_type: TRANSACTION_TYPE_ACCOUNT_SET,
+            flags: None,
+            set_flag: Some(AccountSetFlag::DefaultRipple),
+            clear_flag: None,
+            domain: None,
+            email_hash: None,
+            message_key: None,
+            transfer_rate: None,
+            tick_size: None,
+            nftoken_minter: None,
+        });
+        assert_serialized(&tx, "53545800120003240000000120210000000868400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
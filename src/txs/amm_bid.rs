@@ -0,0 +1,96 @@
+use std::io::{self, Write};
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::{Amount, AnyAmount, Issue};
+use crate::serialize::{
+    serialize_array, BinaryFormatWithoutFieldUid, Serialize, XrplBinaryField,
+    FIELD_ACCOUNT, FIELD_AUTH_ACCOUNT, TYPE_ACCOUNT_ID, TYPE_STOBJECT,
+};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_AMM_BID: i16 = 39;
+
+/// Account that trades at the discounted fee together with the auction slot holder
+#[derive(Clone, Debug)]
+pub struct AuthAccount {
+    pub account: Address,
+}
+
+/// `AuthAccounts` array of `AuthAccount` objects
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, Vec<AuthAccount>> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        serialize_array(writer, self.0, &TYPE_STOBJECT, FIELD_AUTH_ACCOUNT, |auth_account, writer| {
+            XrplBinaryField {
+                xrpl_type: &TYPE_ACCOUNT_ID,
+                field_code: FIELD_ACCOUNT,
+                value: &auth_account.account,
+            }.serialize(writer)
+        })
+    }
+}
+
+/// Bid LP tokens for the auction slot of an AMM (discounted trading fee for 24 hours)
+#[derive(BinarySerialize, Clone)]
+pub struct AMMBidTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+    #[binary(id = "Asset", rtype = "Issue")]
+    pub asset: Issue,
+    #[binary(id = "Asset2", rtype = "Issue")]
+    pub asset2: Issue,
+    /// Minimum LP tokens to pay
+    #[binary(id = "BidMin", rtype = "Amount")]
+    pub bid_min: Option<Amount>,
+    /// Maximum LP tokens to pay
+    #[binary(id = "BidMax", rtype = "Amount")]
+    pub bid_max: Option<Amount>,
+    /// Up to 4 accounts
+    #[binary(id = "AuthAccounts", rtype = "STArray")]
+    pub auth_accounts: Option<Vec<AuthAccount>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::Issue;
+    use crate::txs::amm_bid::{AMMBidTransaction, AuthAccount, TRANSACTION_TYPE_AMM_BID};
+    use crate::txs::test_utils::{address, assert_serialized, ISSUER};
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(AMMBidTransaction {
+            transaction_type: TRANSACTION_TYPE_AMM_BID,
+            flags: None,
+            asset: Issue::Xrp,
+            asset2: Issue::Token { currency: "USD".to_owned(), issuer: address(ISSUER) },
+            bid_min: None,
+            bid_max: None,
+            auth_accounts: Some(vec![
+                AuthAccount { account: address(ISSUER) },
+                AuthAccount { account: address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW") },
+            ]),
+        });
+        assert_serialized(&tx, "53545800120027240000000168400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4CF019E01B81144B4E9C06F24296074F7BC48F92A97916C6DC5EA9E1E01B8114204288D2E47F8EF6C99BCC457966320D12409711E1F103180000000000000000000000000000000000000000041800000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9");
+    }
+}
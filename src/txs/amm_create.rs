@@ -0,0 +1,45 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_AMM_CREATE: i16 = 35;
+
+/// Create an AMM for the pair of the amounts' currencies and deposit the amounts
+///
+/// The fee is the owner reserve increment rather than the usual transaction cost.
+#[derive(BinarySerialize, Clone)]
+pub struct AMMCreateTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: AnyAmount,
+    #[binary(id = "Amount2", rtype = "Amount")]
+    pub amount2: AnyAmount,
+    /// In units of 1/100000 (maximum 1000, that is 1%)
+    #[binary(id = "TradingFee", rtype = "UInt16")]
+    pub trading_fee: u16,
+}
@@ -0,0 +1,39 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::{AnyAmount, Issue};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_AMM_DELETE: i16 = 40;
+
+/// Delete an empty AMM (with too many trust lines to be deleted by the last withdrawal)
+#[derive(BinarySerialize, Clone)]
+pub struct AMMDeleteTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+    #[binary(id = "Asset", rtype = "Issue")]
+    pub asset: Issue,
+    #[binary(id = "Asset2", rtype = "Issue")]
+    pub asset2: Issue,
+}
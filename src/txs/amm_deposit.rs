@@ -0,0 +1,90 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::{Amount, AnyAmount, Issue};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_AMM_DEPOSIT: i16 = 36;
+
+transaction_flags! {
+    /// Deposit mode; exactly one must be set.
+    AMMDepositFlags {
+        /// Both assets, for `lp_token_out`
+        LP_TOKEN = 0x00010000,
+        /// `amount` of one asset
+        SINGLE_ASSET = 0x00080000,
+        /// Up to `amount` and `amount2`
+        TWO_ASSET = 0x00100000,
+        /// Up to `amount` of one asset, for `lp_token_out`
+        ONE_ASSET_LP_TOKEN = 0x00200000,
+        /// Up to `amount` of one asset, paying at most `e_price` per LP token
+        LIMIT_LP_TOKEN = 0x00400000,
+        /// `amount` and `amount2` into an empty AMM, with `trading_fee`
+        TWO_ASSET_IF_EMPTY = 0x00800000,
+    }
+}
+
+#[derive(BinarySerialize, Clone)]
+pub struct AMMDepositTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: AMMDepositFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+    #[binary(id = "Asset", rtype = "Issue")]
+    pub asset: Issue,
+    #[binary(id = "Asset2", rtype = "Issue")]
+    pub asset2: Issue,
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: Option<AnyAmount>,
+    #[binary(id = "Amount2", rtype = "Amount")]
+    pub amount2: Option<AnyAmount>,
+    /// Maximum effective price, in the deposited asset, per LP token received
+    #[binary(id = "EPrice", rtype = "Amount")]
+    pub e_price: Option<AnyAmount>,
+    #[binary(id = "LPTokenOut", rtype = "Amount")]
+    pub lp_token_out: Option<Amount>,
+    /// Only with `TWO_ASSET_IF_EMPTY`
+    #[binary(id = "TradingFee", rtype = "UInt16")]
+    pub trading_fee: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::{AnyAmount, Issue};
+    use crate::txs::amm_deposit::{AMMDepositFlags, AMMDepositTransaction, TRANSACTION_TYPE_AMM_DEPOSIT};
+    use crate::txs::test_utils::{address, assert_serialized, usd, ISSUER};
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(AMMDepositTransaction {
+            transaction_type: TRANSACTION_TYPE_AMM_DEPOSIT,
+            flags: AMMDepositFlags::TWO_ASSET,
+            asset: Issue::Xrp,
+            asset2: Issue::Token { currency: "USD".to_owned(), issuer: address(ISSUER) },
+            amount: Some(AnyAmount::Xrp(1000000)),
+            amount2: Some(AnyAmount::Token(usd(5.0))),
+            e_price: None,
+            lp_token_out: None,
+            trading_fee: None,
+        });
+        assert_serialized(&tx, "53545800120024220010000024000000016140000000000F424068400000000000000C6BD491C37937E0800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA981147CCFE86388B264396710C29F69025DB1DFA4AE4C03180000000000000000000000000000000000000000041800000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9");
+    }
+}
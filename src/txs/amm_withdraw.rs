@@ -0,0 +1,66 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::{Amount, AnyAmount, Issue};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_AMM_WITHDRAW: i16 = 37;
+
+transaction_flags! {
+    /// Withdrawal mode; exactly one must be set.
+    AMMWithdrawFlags {
+        /// Both assets, for `lp_token_in`
+        LP_TOKEN = 0x00010000,
+        /// Both assets, for all LP tokens held
+        WITHDRAW_ALL = 0x00020000,
+        /// One asset (`amount`'s currency), for all LP tokens held
+        ONE_ASSET_WITHDRAW_ALL = 0x00040000,
+        /// `amount` of one asset
+        SINGLE_ASSET = 0x00080000,
+        /// Up to `amount` and `amount2`
+        TWO_ASSET = 0x00100000,
+        /// One asset (at least `amount`), for `lp_token_in`
+        ONE_ASSET_LP_TOKEN = 0x00200000,
+        /// Up to `amount` of one asset, receiving at least `e_price` per LP token
+        LIMIT_LP_TOKEN = 0x00400000,
+    }
+}
+
+#[derive(BinarySerialize, Clone)]
+pub struct AMMWithdrawTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: AMMWithdrawFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+    #[binary(id = "Asset", rtype = "Issue")]
+    pub asset: Issue,
+    #[binary(id = "Asset2", rtype = "Issue")]
+    pub asset2: Issue,
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: Option<AnyAmount>,
+    #[binary(id = "Amount2", rtype = "Amount")]
+    pub amount2: Option<AnyAmount>,
+    /// Minimum effective price, in the withdrawn asset, per LP token returned
+    #[binary(id = "EPrice", rtype = "Amount")]
+    pub e_price: Option<AnyAmount>,
+    #[binary(id = "LPTokenIn", rtype = "Amount")]
+    pub lp_token_in: Option<Amount>,
+}
@@ -0,0 +1,119 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::txs::multisign::Signer;
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_CHECK_CASH: i16 = 17;
+
+/// How much to receive by cashing a check
+#[derive(Clone, Debug)]
+pub enum CheckCashAmount {
+    /// Exactly this amount (`Amount`)
+    Exact(AnyAmount),
+    /// As much as possible, but at least this amount (`DeliverMin`)
+    AtLeast(AnyAmount),
+}
+
+/// Redeem a check, as its destination
+#[derive(Clone)]
+pub struct CheckCashTransaction {
+    pub account: Address,
+    pub transaction_type: i16,
+    pub fee: Option<AnyAmount>,
+    pub sequence: Option<u32>,
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    pub flags: Option<u32>,
+    pub last_ledger_sequence: Option<u32>,
+    pub source_tag: Option<u32>,
+    pub public_key: Option<AccountPublicKey>,
+    pub ticket_sequence: Option<u32>,
+    pub signature: Option<Vec<u8>>,
+
+    /// See `objects::check::check_id`.
+    pub check_id: Hash<32>,
+    pub amount: CheckCashAmount,
+}
+
+/// Binary layout of `CheckCashTransaction`, where `Amount` and `DeliverMin` are separate fields
+#[derive(BinarySerialize)]
+#[binary(serializer_only)]
+struct CheckCashFields {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "CheckID", rtype = "Hash256")]
+    pub check_id: Hash<32>,
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: Option<AnyAmount>,
+    #[binary(id = "DeliverMin", rtype = "Amount")]
+    pub deliver_min: Option<AnyAmount>,
+}
+
+impl Transaction for CheckCashTransaction {
+    fn set_signature(&mut self, signature: Vec<u8>) {
+        self.signature = Some(signature);
+    }
+    fn set_public_key(&mut self, public_key: AccountPublicKey) {
+        self.public_key = Some(public_key);
+    }
+}
+
+impl CheckCashTransaction {
+    fn fields(&self) -> CheckCashFields {
+        let (amount, deliver_min) = match &self.amount {
+            CheckCashAmount::Exact(amount) => (Some(amount.clone()), None),
+            CheckCashAmount::AtLeast(amount) => (None, Some(amount.clone())),
+        };
+        CheckCashFields {
+            account: self.account.clone(),
+            transaction_type: self.transaction_type,
+            fee: self.fee.clone(),
+            sequence: self.sequence,
+            account_txn_id: self.account_txn_id.clone(),
+            flags: self.flags,
+            last_ledger_sequence: self.last_ledger_sequence,
+            source_tag: self.source_tag,
+            public_key: self.public_key.clone(),
+            ticket_sequence: self.ticket_sequence,
+            signature: self.signature.clone(),
+            check_id: self.check_id.clone(),
+            amount,
+            deliver_min,
+        }
+    }
+}
+
+impl TransactionSerializer for CheckCashTransaction {
+    fn serialize(&self, prefix: &[u8; 4], writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+        self.fields().serialize(prefix, writer)
+    }
+    fn serialize_multisigned(&self,
+                             prefix: &[u8; 4],
+                             signers: &[Signer],
+                             writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+        self.fields().serialize_multisigned(prefix, signers, writer)
+    }
+}
@@ -0,0 +1,48 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_CHECK_CREATE: i16 = 16;
+
+/// Create a check, see `objects::check::check_id` for its ID.
+#[derive(BinarySerialize, Clone)]
+pub struct CheckCreateTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "Destination", rtype = "AccountID")]
+    pub destination: Address,
+    /// Maximum amount (including transfer fees) the check can debit
+    #[binary(id = "SendMax", rtype = "Amount")]
+    pub send_max: AnyAmount,
+    #[binary(id = "DestinationTag", rtype = "UInt32")]
+    pub destination_tag: Option<u32>,
+    /// In seconds since the Ripple Epoch
+    #[binary(id = "Expiration", rtype = "UInt32")]
+    pub expiration: Option<u32>,
+    #[binary(id = "InvoiceID", rtype = "Hash256")]
+    pub invoice_id: Option<Hash<32>>,
+}
@@ -0,0 +1,53 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_ESCROW_CREATE: i16 = 1;
+
+/// Lock funds until a time passes or a crypto-condition is fulfilled
+///
+/// At least one of `finish_after` and `condition` must be specified.
+#[derive(BinarySerialize, Clone)]
+pub struct EscrowCreateTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: AnyAmount,
+    #[binary(id = "Destination", rtype = "AccountID")]
+    pub destination: Address,
+    #[binary(id = "DestinationTag", rtype = "UInt32")]
+    pub destination_tag: Option<u32>,
+    /// The escrow can be cancelled after this time (seconds since the Ripple Epoch).
+    #[binary(id = "CancelAfter", rtype = "UInt32")]
+    pub cancel_after: Option<u32>,
+    /// The escrow can be finished after this time (seconds since the Ripple Epoch).
+    #[binary(id = "FinishAfter", rtype = "UInt32")]
+    pub finish_after: Option<u32>,
+    /// DER-encoded crypto-condition, see `crypto_condition`
+    #[binary(id = "Condition", rtype = "Blob")]
+    pub condition: Option<Vec<u8>>,
+}
@@ -0,0 +1,49 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_ESCROW_FINISH: i16 = 2;
+
+/// Deliver escrowed funds to the destination
+///
+/// With a fulfillment the fee is at least 330 drops plus 10 drops per 16 bytes of the fulfillment.
+#[derive(BinarySerialize, Clone)]
+pub struct EscrowFinishTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// Creator of the escrow
+    #[binary(id = "Owner", rtype = "AccountID")]
+    pub owner: Address,
+    /// `Sequence` (or `TicketSequence`) of the `EscrowCreate`
+    #[binary(id = "OfferSequence", rtype = "UInt32")]
+    pub offer_sequence: u32,
+    /// Must match the escrow's condition.
+    #[binary(id = "Condition", rtype = "Blob")]
+    pub condition: Option<Vec<u8>>,
+    #[binary(id = "Fulfillment", rtype = "Blob")]
+    pub fulfillment: Option<Vec<u8>>,
+}
@@ -2,7 +2,107 @@ use xrpl_binary_codec::sign::sign;
 use xrpl_binary_codec::serializer::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE;
 use crate::hashes::{AccountPublicKey, SecretKey};
 
+/// Define a set of transaction flags (`tf...`), serialized as `UInt32`.
+macro_rules! transaction_flags {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$flag_meta:meta])* $flag:ident = $value:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $name(pub u32);
+
+        impl $name {
+            $($(#[$flag_meta])* pub const $flag: $name = $name($value);)*
+
+            pub fn contains(self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = $name;
+            fn bitor(self, rhs: $name) -> $name {
+                $name(self.0 | rhs.0)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: $name) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl<'a> crate::serialize::Serialize for crate::serialize::BinaryFormatWithoutFieldUid<'a, $name> {
+            fn serialize(&self, writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+                crate::serialize::Serialize::serialize(&crate::serialize::BinaryFormatWithoutFieldUid(&self.0.0), writer)
+            }
+        }
+    };
+}
+
+/// Transaction `$tx` by `test_utils::ACCOUNT` with `Fee` 12 drops, `Sequence` 1, `flags`
+/// and the type-specific fields; the other common fields are `None`.
+#[cfg(test)]
+macro_rules! test_transaction {
+    (
+        $tx:ident {
+            transaction_type: $transaction_type:expr,
+            flags: $flags:expr,
+            $($field:ident: $value:expr),* $(,)?
+        }
+    ) => {
+        $tx {
+            account: crate::txs::test_utils::address(crate::txs::test_utils::ACCOUNT),
+            transaction_type: $transaction_type,
+            fee: Some(crate::objects::amount::AnyAmount::Xrp(12)),
+            sequence: Some(1),
+            account_txn_id: None,
+            flags: $flags,
+            last_ledger_sequence: None,
+            source_tag: None,
+            public_key: None,
+            ticket_sequence: None,
+            signature: None,
+            $($field: $value,)*
+        }
+    };
+}
+
+pub mod account_delete;
+pub mod account_set;
+pub mod amm_bid;
+pub mod amm_create;
+pub mod amm_delete;
+pub mod amm_deposit;
+pub mod amm_vote;
+pub mod amm_withdraw;
+pub mod check_cancel;
+pub mod check_cash;
+pub mod check_create;
+pub mod clawback;
+pub mod escrow_cancel;
+pub mod escrow_create;
+pub mod escrow_finish;
+pub mod multisign;
+pub mod nftoken_accept_offer;
+pub mod nftoken_burn;
+pub mod nftoken_cancel_offer;
+pub mod nftoken_create_offer;
+pub mod nftoken_mint;
+pub mod nftoken_modify;
+pub mod offer_cancel;
+pub mod offer_create;
 pub mod payment;
+pub mod payment_channel_claim;
+pub mod payment_channel_create;
+pub mod payment_channel_fund;
+pub mod set_regular_key;
+pub mod signer_list_set;
+pub mod ticket_create;
+pub mod trust_set;
 
 pub trait Transaction: TransactionSerializer {
     fn set_signature(&mut self, signature: Vec<u8>);
@@ -11,6 +111,17 @@ pub trait Transaction: TransactionSerializer {
 
 pub trait TransactionSerializer {
     fn serialize(&self, prefix: &[u8; 4], writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()>;
+    /// Serialize as multi-signed: with empty `SigningPubKey`, without `TxnSignature`
+    /// (`public_key` and `signature` are ignored) and with the `Signers` array,
+    /// if `signers` is not empty (see `multisign`).
+    ///
+    /// Implemented by `derive(BinarySerialize)`; unsupported by default.
+    fn serialize_multisigned(&self,
+                             _prefix: &[u8; 4],
+                             _signers: &[multisign::Signer],
+                             _writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+        Err(::std::io::Error::new(::std::io::ErrorKind::Unsupported, "Multi-signing is not supported"))
+    }
 }
 
 pub fn sign_transaction<T: Transaction>(tx: T, public_key: &AccountPublicKey, secret_key: &SecretKey) -> T {
@@ -23,3 +134,34 @@ pub fn sign_transaction<T: Transaction>(tx: T, public_key: &AccountPublicKey, se
     tx
 }
 
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use xrpl_binary_codec::serializer::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE;
+    use crate::hashes::Address;
+    use crate::objects::amount::Amount;
+    use crate::txs::TransactionSerializer;
+
+    /// Sender of the transactions built by `test_transaction!`
+    pub const ACCOUNT: &str = "rU4Ai74ohgtUP8evP3qd2HuxWSFvLVt7uh";
+    /// Issuer of `usd`, also used as a counterparty
+    pub const ISSUER: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    pub fn address(address: &str) -> Address {
+        Address::decode(address).unwrap()
+    }
+
+    pub fn usd(value: f64) -> Amount {
+        Amount {
+            value,
+            currency: "USD".to_owned(),
+            issuer: address(ISSUER),
+        }
+    }
+
+    /// Check `tx` serialized with the `STX\0` prefix against the upper case hex `expected`.
+    pub fn assert_serialized<T: TransactionSerializer>(tx: &T, expected: &str) {
+        let mut ser = Vec::new();
+        T::serialize(tx, &HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE, &mut ser).unwrap();
+        assert_eq!(hex::encode_upper(ser), expected);
+    }
+}
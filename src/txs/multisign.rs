@@ -0,0 +1,203 @@
+use std::io::{self, Write};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use xrpl_binary_codec::sign::sign;
+use crate::hashes::{AccountPublicKey, Address, SecretKey};
+use crate::serialize::{
+    self, serialize_array, BinaryFormatWithoutFieldUid, XrplBinaryField,
+    FIELD_ACCOUNT, FIELD_SIGNER, FIELD_SIGNING_PUB_KEY, FIELD_TXN_SIGNATURE, TYPE_ACCOUNT_ID, TYPE_BLOB, TYPE_STOBJECT,
+};
+use crate::serialize::Serialize as _;
+use crate::txs::Transaction;
+
+/// `SMT\0`
+pub const HASH_PREFIX_TRANSACTION_MULTI_SIGN: [u8; 4] = [0x53, 0x4D, 0x54, 0x00];
+
+/// Signature of a multi-signed transaction by one of the signers
+#[derive(Clone, Debug)]
+pub struct Signer {
+    pub account: Address,
+    pub public_key: AccountPublicKey,
+    pub signature: Vec<u8>,
+}
+
+/// As an element of the `Signers` array in JSON
+impl Serialize for Signer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Serialize)]
+        struct Signer2<'a> {
+            #[serde(rename = "Account")]
+            account: &'a Address,
+            #[serde(rename = "SigningPubKey")]
+            public_key: String,
+            #[serde(rename = "TxnSignature")]
+            signature: String,
+        }
+        #[derive(Serialize)]
+        struct SignerWrapper<'a> {
+            #[serde(rename = "Signer")]
+            signer: Signer2<'a>,
+        }
+        SignerWrapper {
+            signer: Signer2 {
+                account: &self.account,
+                public_key: self.public_key.encode_hex(),
+                signature: hex::encode_upper(&self.signature),
+            },
+        }.serialize(serializer)
+    }
+}
+
+/// `Signers` array (see `combine_signers`), in binary format
+pub struct Signers<'s>(pub &'s [Signer]);
+
+impl<'a, 's> serialize::Serialize for BinaryFormatWithoutFieldUid<'a, Signers<'s>> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        serialize_array(writer, self.0.0, &TYPE_STOBJECT, FIELD_SIGNER, |signer, writer| {
+            XrplBinaryField {
+                xrpl_type: &TYPE_BLOB,
+                field_code: FIELD_SIGNING_PUB_KEY,
+                value: &signer.public_key.bytes_without_prefix().to_vec(),
+            }.serialize(writer)?;
+            XrplBinaryField {
+                xrpl_type: &TYPE_BLOB,
+                field_code: FIELD_TXN_SIGNATURE,
+                value: &signer.signature,
+            }.serialize(writer)?;
+            XrplBinaryField {
+                xrpl_type: &TYPE_ACCOUNT_ID,
+                field_code: FIELD_ACCOUNT,
+                value: &signer.account,
+            }.serialize(writer)
+        })
+    }
+}
+
+/// Data signed by `account` to multi-sign `tx`: the transaction (with empty `SigningPubKey`, without `TxnSignature`)
+/// prefixed by `SMT\0` and suffixed by the signer's account ID.
+pub fn multisigning_data<T: Transaction>(tx: &T, account: &Address) -> io::Result<Vec<u8>> {
+    let mut ser = Vec::new();
+    tx.serialize_multisigned(&HASH_PREFIX_TRANSACTION_MULTI_SIGN, &[], &mut ser)?;
+    ser.extend_from_slice(&account.0.0);
+    Ok(ser)
+}
+
+/// Sign `tx` as one of the signers of a multi-signed transaction.
+///
+/// Unlike `sign_transaction`, the transaction is not modified: its `public_key` and `signature` are ignored.
+/// The signatures of all signers are then put together by `combine_signers`.
+pub fn multisign<T: Transaction>(tx: &T,
+                                 account: &Address,
+                                 public_key: &AccountPublicKey,
+                                 secret_key: &SecretKey)
+                                 -> io::Result<Signer>
+{
+    let ser = multisigning_data(tx, account)?;
+    Ok(Signer {
+        account: account.clone(),
+        public_key: public_key.clone(),
+        signature: sign(ser.as_slice(), secret_key.0.0.as_slice()),
+    })
+}
+
+/// Merge signatures collected independently into a `Signers` array,
+/// sorted by account ID (as required) and without duplicate signers.
+pub fn combine_signers<I: IntoIterator<Item = Signer>>(signers: I) -> Vec<Signer> {
+    let mut signers: Vec<Signer> = signers.into_iter().collect();
+    signers.sort_by(|a, b| a.account.0.0.cmp(&b.account.0.0));
+    signers.dedup_by(|a, b| a.account == b.account);
+    signers
+}
+
+/// Multi-signed transaction `tx` with `signers` (see `combine_signers`) in binary format,
+/// for `methods::submit::submit` (see also `methods::submit::submit_multisigned_transaction`).
+pub fn multisigned_blob<T: Transaction>(tx: &T, signers: &[Signer]) -> io::Result<Vec<u8>> {
+    if signers.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "A multi-signed transaction must have signers"));
+    }
+    let mut ser = Vec::new();
+    tx.serialize_multisigned(&HASH_PREFIX_TRANSACTION_MULTI_SIGN, signers, &mut ser)?;
+    Ok(ser.split_off(HASH_PREFIX_TRANSACTION_MULTI_SIGN.len()))
+}
+
+/// Put `signers` (see `combine_signers`) and empty `SigningPubKey` into a transaction
+/// in JSON format, for `methods::submit_multisigned`.
+pub fn set_signers(tx_json: &mut Value, signers: &[Signer]) -> Result<(), serde_json::Error> {
+    if let Value::Object(obj) = tx_json {
+        obj.insert("Signers".to_owned(), serde_json::to_value(signers)?);
+        obj.insert("SigningPubKey".to_owned(), Value::String(String::new()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hashes::AccountPublicKey;
+    use crate::txs::account_set::{AccountSetFlag, AccountSetTransaction, TRANSACTION_TYPE_ACCOUNT_SET};
+    use crate::txs::multisign::{multisigned_blob, multisigning_data, Signer};
+    use crate::txs::test_utils::{address, ACCOUNT};
+
+    fn account_set() -> AccountSetTransaction {
+        test_transaction!(AccountSetTransaction {
+            transaction_type: TRANSACTION_TYPE_ACCOUNT_SET,
+            flags: None,
+            set_flag: Some(AccountSetFlag::DefaultRipple),
+            clear_flag: None,
+            domain: None,
+            email_hash: None,
+            message_key: None,
+            transfer_rate: None,
+            tick_size: None,
+            nftoken_minter: None,
+        })
+    }
+
+    #[test]
+    fn test_multisigning_data() {
+        let account = address(ACCOUNT);
+        let tx = account_set();
+        assert_eq!(
+            multisigning_data(&tx, &account).unwrap(),
+            hex::decode("534D5400120003240000000120210000000868400000000000000C730081147CCFE86388B264396710C29F69025DB1DFA4AE4C7CCFE86388B264396710C29F69025DB1DFA4AE4C").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_multisigned_blob() {
+        let account = address(ACCOUNT);
+        // Not a real key and signature, only their placement is checked.
+        let signer = Signer {
+            account: account.clone(),
+            public_key: AccountPublicKey::from_bytes_without_prefix([0x02; 33]),
+            signature: vec![0xAB; 4],
+        };
+        let tx = account_set();
+        assert_eq!(
+            hex::encode_upper(multisigned_blob(&tx, &[signer]).unwrap()),
+            // ..., empty `SigningPubKey`, `Account`, `Signers` with `Signer` (`SigningPubKey`, `TxnSignature`, `Account`)
+            "120003240000000120210000000868400000000000000C730081147CCFE86388B264396710C29F69025DB1DFA4AE4C\
+            F3E0107321020202020202020202020202020202020202020202020202020202020202020202\
+            7404ABABABAB81147CCFE86388B264396710C29F69025DB1DFA4AE4CE1F1",
+        );
+        assert!(multisigned_blob(&tx, &[]).is_err());
+    }
+
+    #[test]
+    fn test_multisigned_ignores_signature() {
+        let account = address(ACCOUNT);
+        let signer = Signer {
+            account: account.clone(),
+            public_key: AccountPublicKey::from_bytes_without_prefix([0x02; 33]),
+            signature: vec![0xAB; 4],
+        };
+        let unsigned = account_set();
+        let mut signed = account_set();
+        signed.public_key = Some(AccountPublicKey::from_bytes_without_prefix([0x03; 33]));
+        signed.signature = Some(vec![0xCD; 4]);
+        assert_eq!(multisigning_data(&signed, &account).unwrap(), multisigning_data(&unsigned, &account).unwrap());
+        let blob = multisigned_blob(&signed, &[signer.clone()]).unwrap();
+        assert_eq!(blob, multisigned_blob(&unsigned, &[signer]).unwrap());
+        // No `TxnSignature` of the transaction itself
+        assert!(!hex::encode_upper(blob).contains("7404CDCDCDCD"));
+    }
+}
@@ -0,0 +1,147 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::txs::multisign::Signer;
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_NFTOKEN_ACCEPT_OFFER: i16 = 29;
+
+/// Offers accepted by `NFTokenAcceptOffer`
+#[derive(Clone, Debug)]
+pub enum NFTokenAcceptOfferMode {
+    /// Accept a sell offer
+    Sell(Hash<32>),
+    /// Accept a buy offer
+    Buy(Hash<32>),
+    /// Match a sell offer with a buy offer, keeping the difference up to `broker_fee`.
+    Brokered {
+        sell_offer: Hash<32>,
+        buy_offer: Hash<32>,
+        broker_fee: Option<AnyAmount>,
+    },
+}
+
+#[derive(Clone)]
+pub struct NFTokenAcceptOfferTransaction {
+    pub account: Address,
+    pub transaction_type: i16,
+    pub fee: Option<AnyAmount>,
+    pub sequence: Option<u32>,
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    pub flags: Option<u32>,
+    pub last_ledger_sequence: Option<u32>,
+    pub source_tag: Option<u32>,
+    pub public_key: Option<AccountPublicKey>,
+    pub ticket_sequence: Option<u32>,
+    pub signature: Option<Vec<u8>>,
+
+    pub mode: NFTokenAcceptOfferMode,
+}
+
+/// Binary layout of `NFTokenAcceptOfferTransaction`
+#[derive(BinarySerialize)]
+#[binary(serializer_only)]
+struct NFTokenAcceptOfferFields {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "NFTokenSellOffer", rtype = "Hash256")]
+    pub nftoken_sell_offer: Option<Hash<32>>,
+    #[binary(id = "NFTokenBuyOffer", rtype = "Hash256")]
+    pub nftoken_buy_offer: Option<Hash<32>>,
+    #[binary(id = "NFTokenBrokerFee", rtype = "Amount")]
+    pub nftoken_broker_fee: Option<AnyAmount>,
+}
+
+impl Transaction for NFTokenAcceptOfferTransaction {
+    fn set_signature(&mut self, signature: Vec<u8>) {
+        self.signature = Some(signature);
+    }
+    fn set_public_key(&mut self, public_key: AccountPublicKey) {
+        self.public_key = Some(public_key);
+    }
+}
+
+impl NFTokenAcceptOfferTransaction {
+    fn fields(&self) -> NFTokenAcceptOfferFields {
+        let (nftoken_sell_offer, nftoken_buy_offer, nftoken_broker_fee) = match &self.mode {
+            NFTokenAcceptOfferMode::Sell(offer) => (Some(offer.clone()), None, None),
+            NFTokenAcceptOfferMode::Buy(offer) => (None, Some(offer.clone()), None),
+            NFTokenAcceptOfferMode::Brokered { sell_offer, buy_offer, broker_fee } =>
+                (Some(sell_offer.clone()), Some(buy_offer.clone()), broker_fee.clone()),
+        };
+        NFTokenAcceptOfferFields {
+            account: self.account.clone(),
+            transaction_type: self.transaction_type,
+            fee: self.fee.clone(),
+            sequence: self.sequence,
+            account_txn_id: self.account_txn_id.clone(),
+            flags: self.flags,
+            last_ledger_sequence: self.last_ledger_sequence,
+            source_tag: self.source_tag,
+            public_key: self.public_key.clone(),
+            ticket_sequence: self.ticket_sequence,
+            signature: self.signature.clone(),
+            nftoken_sell_offer,
+            nftoken_buy_offer,
+            nftoken_broker_fee,
+        }
+    }
+}
+
+impl TransactionSerializer for NFTokenAcceptOfferTransaction {
+    fn serialize(&self, prefix: &[u8; 4], writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+        self.fields().serialize(prefix, writer)
+    }
+    fn serialize_multisigned(&self,
+                             prefix: &[u8; 4],
+                             signers: &[Signer],
+                             writer: &mut dyn ::std::io::Write) -> ::std::io::Result<()> {
+        self.fields().serialize_multisigned(prefix, signers, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::nftoken_accept_offer::{NFTokenAcceptOfferMode, NFTokenAcceptOfferTransaction, TRANSACTION_TYPE_NFTOKEN_ACCEPT_OFFER};
+    use crate::txs::test_utils::assert_serialized;
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize_brokered() {
+        let tx = test_transaction!(NFTokenAcceptOfferTransaction {
+            transaction_type: TRANSACTION_TYPE_NFTOKEN_ACCEPT_OFFER,
+            flags: None,
+            mode: NFTokenAcceptOfferMode::Brokered {
+                sell_offer: Hash::from_hex("68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77").unwrap(),
+                buy_offer: Hash::from_hex("1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F708192A3B4C5D6E7F809").unwrap(),
+                broker_fee: Some(AnyAmount::Xrp(100)),
+            },
+        });
+        assert_serialized(&tx, "5354580012001D2400000001501C1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F708192A3B4C5D6E7F809501D68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B7768400000000000000C6013400000000000006481147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
@@ -0,0 +1,58 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_NFTOKEN_CANCEL_OFFER: i16 = 28;
+
+#[derive(BinarySerialize, Clone)]
+pub struct NFTokenCancelOfferTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// IDs of the `NFTokenOffer` objects to cancel
+    #[binary(id = "NFTokenOffers", rtype = "Vector256")]
+    pub nftoken_offers: Vec<Hash<32>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::nftoken_cancel_offer::{NFTokenCancelOfferTransaction, TRANSACTION_TYPE_NFTOKEN_CANCEL_OFFER};
+    use crate::txs::test_utils::assert_serialized;
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(NFTokenCancelOfferTransaction {
+            transaction_type: TRANSACTION_TYPE_NFTOKEN_CANCEL_OFFER,
+            flags: None,
+            nftoken_offers: vec![
+                Hash::from_hex("68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77").unwrap(),
+                Hash::from_hex("1A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F708192A3B4C5D6E7F809").unwrap(),
+            ],
+        });
+        assert_serialized(&tx, "5354580012001C240000000168400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C04134068CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B771A2B3C4D5E6F708192A3B4C5D6E7F8091A2B3C4D5E6F708192A3B4C5D6E7F809");
+    }
+}
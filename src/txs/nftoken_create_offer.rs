@@ -0,0 +1,78 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_NFTOKEN_CREATE_OFFER: i16 = 27;
+
+transaction_flags! {
+    NFTokenCreateOfferFlags {
+        /// Sell offer (otherwise buy offer)
+        SELL_NFTOKEN = 0x00000001,
+    }
+}
+
+/// Offer to buy or sell an NFT
+#[derive(BinarySerialize, Clone)]
+pub struct NFTokenCreateOfferTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: NFTokenCreateOfferFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "NFTokenID", rtype = "Hash256")]
+    pub nftoken_id: Hash<32>,
+    /// Price; may be zero for sell offers.
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: AnyAmount,
+    /// Holder of the token, required for buy offers only.
+    #[binary(id = "Owner", rtype = "AccountID")]
+    pub owner: Option<Address>,
+    /// Only this account may accept the offer.
+    #[binary(id = "Destination", rtype = "AccountID")]
+    pub destination: Option<Address>,
+    /// In seconds since the Ripple Epoch
+    #[binary(id = "Expiration", rtype = "UInt32")]
+    pub expiration: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::nftoken_create_offer::{NFTokenCreateOfferFlags, NFTokenCreateOfferTransaction, TRANSACTION_TYPE_NFTOKEN_CREATE_OFFER};
+    use crate::txs::test_utils::{address, assert_serialized, ISSUER};
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(NFTokenCreateOfferTransaction {
+            transaction_type: TRANSACTION_TYPE_NFTOKEN_CREATE_OFFER,
+            flags: NFTokenCreateOfferFlags::SELL_NFTOKEN,
+            nftoken_id: Hash::from_hex("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65").unwrap(),
+            amount: AnyAmount::Xrp(1000000),
+            owner: None,
+            destination: Some(address(ISSUER)),
+            expiration: None,
+        });
+        assert_serialized(&tx, "5354580012001B220000000124000000015A000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D656140000000000F424068400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C83144B4E9C06F24296074F7BC48F92A97916C6DC5EA9");
+    }
+}
@@ -0,0 +1,93 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_NFTOKEN_MINT: i16 = 25;
+
+transaction_flags! {
+    NFTokenMintFlags {
+        /// The issuer (or an authorized minter) may burn the token.
+        BURNABLE = 0x00000001,
+        /// The token can only be sold for XRP.
+        ONLY_XRP = 0x00000002,
+        /// Create trust lines for transfer fees automatically (deprecated).
+        TRUST_LINE = 0x00000004,
+        /// The token can be transferred not only to or from the issuer.
+        TRANSFERABLE = 0x00000008,
+        /// `URI` can be changed by `NFTokenModify`.
+        MUTABLE = 0x00000010,
+    }
+}
+
+/// Mint an NFT, optionally with a sell offer (`amount`, `destination`, `expiration`)
+#[derive(BinarySerialize, Clone)]
+pub struct NFTokenMintTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: NFTokenMintFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// Arbitrary taxon (collection) of the token
+    #[binary(id = "NFTokenTaxon", rtype = "UInt32")]
+    pub nftoken_taxon: u32,
+    /// Issuer, if minted by an authorized minter on behalf of it
+    #[binary(id = "Issuer", rtype = "AccountID")]
+    pub issuer: Option<Address>,
+    /// Fee for secondary sales, in units of 1/100000 (maximum 50000); requires `TRANSFERABLE`.
+    #[binary(id = "TransferFee", rtype = "UInt16")]
+    pub transfer_fee: Option<u16>,
+    /// Up to 256 bytes, usually a URI of the token's data
+    #[binary(id = "URI", rtype = "Blob")]
+    pub uri: Option<Vec<u8>>,
+    /// Price of the sell offer created with the token
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: Option<AnyAmount>,
+    /// Only this account may accept the sell offer.
+    #[binary(id = "Destination", rtype = "AccountID")]
+    pub destination: Option<Address>,
+    /// Expiration of the sell offer (seconds since the Ripple Epoch)
+    #[binary(id = "Expiration", rtype = "UInt32")]
+    pub expiration: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::nftoken_mint::{NFTokenMintFlags, NFTokenMintTransaction, TRANSACTION_TYPE_NFTOKEN_MINT};
+    use crate::txs::test_utils::assert_serialized;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(NFTokenMintTransaction {
+            transaction_type: TRANSACTION_TYPE_NFTOKEN_MINT,
+            flags: NFTokenMintFlags::TRANSFERABLE,
+            nftoken_taxon: 0,
+            issuer: None,
+            transfer_fee: Some(314),
+            uri: Some(b"ipfs://bafy".to_vec()),
+            amount: None,
+            destination: None,
+            expiration: None,
+        });
+        assert_serialized(&tx, "5354580012001914013A22000000082400000001202A0000000068400000000000000C750B697066733A2F2F6261667981147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
@@ -0,0 +1,54 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_OFFER_CANCEL: i16 = 8;
+
+#[derive(BinarySerialize, Clone)]
+pub struct OfferCancelTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// `Sequence` (or `TicketSequence`) of the `OfferCreate` that placed the offer
+    #[binary(id = "OfferSequence", rtype = "UInt32")]
+    pub offer_sequence: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::offer_cancel::{OfferCancelTransaction, TRANSACTION_TYPE_OFFER_CANCEL};
+    use crate::txs::test_utils::assert_serialized;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(OfferCancelTransaction {
+            transaction_type: TRANSACTION_TYPE_OFFER_CANCEL,
+            flags: None,
+            offer_sequence: 7,
+        });
+        assert_serialized(&tx, "53545800120008240000000120190000000768400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
@@ -0,0 +1,80 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_OFFER_CREATE: i16 = 7;
+
+transaction_flags! {
+    OfferCreateFlags {
+        /// Do not consume offers that exactly match this one.
+        PASSIVE = 0x00010000,
+        /// Never place the offer in the order book, only take matching offers.
+        IMMEDIATE_OR_CANCEL = 0x00020000,
+        /// Fail unless the whole `taker_pays` can be taken immediately.
+        FILL_OR_KILL = 0x00040000,
+        /// Exchange the whole `taker_gets`, even for more than `taker_pays`.
+        SELL = 0x00080000,
+    }
+}
+
+/// Place an offer in the decentralized exchange
+#[derive(BinarySerialize, Clone)]
+pub struct OfferCreateTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: OfferCreateFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// Amount the offer creator gives
+    #[binary(id = "TakerGets", rtype = "Amount")]
+    pub taker_gets: AnyAmount,
+    /// Amount the offer creator receives
+    #[binary(id = "TakerPays", rtype = "Amount")]
+    pub taker_pays: AnyAmount,
+    /// In seconds since the Ripple Epoch
+    #[binary(id = "Expiration", rtype = "UInt32")]
+    pub expiration: Option<u32>,
+    /// Offer to cancel first (its `Sequence` or `TicketSequence`)
+    #[binary(id = "OfferSequence", rtype = "UInt32")]
+    pub offer_sequence: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::offer_create::{OfferCreateFlags, OfferCreateTransaction, TRANSACTION_TYPE_OFFER_CREATE};
+    use crate::txs::test_utils::{assert_serialized, usd};
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(OfferCreateTransaction {
+            transaction_type: TRANSACTION_TYPE_OFFER_CREATE,
+            flags: OfferCreateFlags::SELL,
+            taker_gets: AnyAmount::Xrp(15000000000),
+            taker_pays: AnyAmount::Token(usd(7072.8)),
+            expiration: Some(595640108),
+            offer_sequence: Some(1752791),
+        });
+        assert_serialized(&tx, "53545800120007220008000024000000012A2380BF2C2019001ABED764D55920AC9391400000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA965400000037E11D60068400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
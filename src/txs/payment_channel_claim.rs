@@ -0,0 +1,82 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_PAYMENT_CHANNEL_CLAIM: i16 = 15;
+
+transaction_flags! {
+    PaymentChannelClaimFlags {
+        /// Clear the channel's expiration (source only).
+        RENEW = 0x00010000,
+        /// Close the channel (immediately if by the destination or if it has no XRP left).
+        CLOSE = 0x00020000,
+    }
+}
+
+/// Redeem XRP from a payment channel, and/or close or renew it
+///
+/// The destination redeems with a claim signed by the source (see `methods::channel_authorize`).
+#[derive(BinarySerialize, Clone)]
+pub struct PaymentChannelClaimTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: PaymentChannelClaimFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "Channel", rtype = "Hash256")]
+    pub channel: Hash<32>,
+    /// Total XRP delivered by the channel after this claim
+    #[binary(id = "Balance", rtype = "Amount")]
+    pub balance: Option<AnyAmount>,
+    /// XRP authorized by `claim_signature`
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: Option<AnyAmount>,
+    /// Signature of the claim (required unless sent by the source)
+    #[binary(id = "Signature", rtype = "Blob")]
+    pub claim_signature: Option<Vec<u8>>,
+    /// Public key of the channel, which signed the claim
+    #[binary(id = "PublicKey", rtype = "Blob")]
+    pub channel_public_key: Option<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::payment_channel_claim::{PaymentChannelClaimFlags, PaymentChannelClaimTransaction, TRANSACTION_TYPE_PAYMENT_CHANNEL_CLAIM};
+    use crate::txs::test_utils::assert_serialized;
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(PaymentChannelClaimTransaction {
+            transaction_type: TRANSACTION_TYPE_PAYMENT_CHANNEL_CLAIM,
+            flags: PaymentChannelClaimFlags::CLOSE,
+            channel: Hash::from_hex("C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198").unwrap(),
+            balance: Some(AnyAmount::Xrp(1000000)),
+            amount: Some(AnyAmount::Xrp(1000000)),
+            claim_signature: Some(hex::decode("30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B").unwrap()),
+            channel_public_key: Some(hex::decode("32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A").unwrap()),
+        });
+        assert_serialized(&tx, "5354580012000F220002000024000000015016C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA61986140000000000F42406240000000000F424068400000000000000C712132D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A764630440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
@@ -0,0 +1,74 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_PAYMENT_CHANNEL_CREATE: i16 = 13;
+
+/// Open a payment channel to `destination`
+#[derive(BinarySerialize, Clone)]
+pub struct PaymentChannelCreateTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// XRP to set aside in the channel
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: AnyAmount,
+    #[binary(id = "Destination", rtype = "AccountID")]
+    pub destination: Address,
+    #[binary(id = "DestinationTag", rtype = "UInt32")]
+    pub destination_tag: Option<u32>,
+    /// Seconds the source must wait to close the channel with unclaimed XRP
+    #[binary(id = "SettleDelay", rtype = "UInt32")]
+    pub settle_delay: u32,
+    /// Key (33 bytes) that will sign the claims
+    #[binary(id = "PublicKey", rtype = "Blob")]
+    pub channel_public_key: Vec<u8>,
+    /// Immutable expiration (seconds since the Ripple Epoch)
+    #[binary(id = "CancelAfter", rtype = "UInt32")]
+    pub cancel_after: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::payment_channel_create::{PaymentChannelCreateTransaction, TRANSACTION_TYPE_PAYMENT_CHANNEL_CREATE};
+    use crate::txs::test_utils::{address, assert_serialized, ISSUER};
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(PaymentChannelCreateTransaction {
+            transaction_type: TRANSACTION_TYPE_PAYMENT_CHANNEL_CREATE,
+            flags: None,
+            amount: AnyAmount::Xrp(10000),
+            destination: address(ISSUER),
+            destination_tag: Some(23480),
+            settle_delay: 86400,
+            channel_public_key: hex::decode("32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A").unwrap(),
+            cancel_after: Some(533171558),
+        });
+        assert_serialized(&tx, "5354580012000D24000000012E00005BB820241FC78D6620270001518061400000000000271068400000000000000C712132D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A81147CCFE86388B264396710C29F69025DB1DFA4AE4C83144B4E9C06F24296074F7BC48F92A97916C6DC5EA9");
+    }
+}
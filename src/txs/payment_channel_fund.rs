@@ -0,0 +1,64 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_PAYMENT_CHANNEL_FUND: i16 = 14;
+
+/// Add XRP to a payment channel and/or extend its expiration (by the source)
+#[derive(BinarySerialize, Clone)]
+pub struct PaymentChannelFundTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    #[binary(id = "Channel", rtype = "Hash256")]
+    pub channel: Hash<32>,
+    /// XRP to add
+    #[binary(id = "Amount", rtype = "Amount")]
+    pub amount: AnyAmount,
+    /// New expiration (seconds since the Ripple Epoch), at least `SettleDelay` from now
+    #[binary(id = "Expiration", rtype = "UInt32")]
+    pub expiration: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::amount::AnyAmount;
+    use crate::txs::payment_channel_fund::{PaymentChannelFundTransaction, TRANSACTION_TYPE_PAYMENT_CHANNEL_FUND};
+    use crate::txs::test_utils::assert_serialized;
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(PaymentChannelFundTransaction {
+            transaction_type: TRANSACTION_TYPE_PAYMENT_CHANNEL_FUND,
+            flags: None,
+            channel: Hash::from_hex("C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198").unwrap(),
+            amount: AnyAmount::Xrp(200000),
+            expiration: Some(543171558),
+        });
+        assert_serialized(&tx, "5354580012000E24000000012A206023E65016C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198614000000000030D4068400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::{self, Write};
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::AnyAmount;
+use crate::serialize::{
+    serialize_array, BinaryFormatWithoutFieldUid, Serialize, XrplBinaryField,
+    FIELD_ACCOUNT, FIELD_SIGNER_ENTRY, FIELD_SIGNER_WEIGHT, FIELD_WALLET_LOCATOR, TYPE_ACCOUNT_ID, TYPE_HASH256, TYPE_STOBJECT, TYPE_UINT16,
+};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_SIGNER_LIST_SET: i16 = 12;
+
+#[derive(Clone, Debug)]
+pub struct SignerEntry {
+    pub account: Address,
+    pub signer_weight: u16,
+    /// Arbitrary data
+    pub wallet_locator: Option<Hash<32>>,
+}
+
+/// `SignerEntries` array of `SignerEntry` objects
+impl<'a> Serialize for BinaryFormatWithoutFieldUid<'a, Vec<SignerEntry>> {
+    fn serialize(&self, writer: &mut dyn Write) -> io::Result<()> {
+        serialize_array(writer, self.0, &TYPE_STOBJECT, FIELD_SIGNER_ENTRY, |entry, writer| {
+            XrplBinaryField {
+                xrpl_type: &TYPE_UINT16,
+                field_code: FIELD_SIGNER_WEIGHT,
+                value: &entry.signer_weight,
+            }.serialize(writer)?;
+            XrplBinaryField {
+                xrpl_type: &TYPE_HASH256,
+                field_code: FIELD_WALLET_LOCATOR,
+                value: &entry.wallet_locator,
+            }.serialize(writer)?;
+            XrplBinaryField {
+                xrpl_type: &TYPE_ACCOUNT_ID,
+                field_code: FIELD_ACCOUNT,
+                value: &entry.account,
+            }.serialize(writer)
+        })
+    }
+}
+
+/// Set or remove the list of multi-signers of the account
+#[derive(BinarySerialize, Clone)]
+pub struct SignerListSetTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    /// Global flags should be zero.
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: Option<u32>,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// Total weight of signatures required; 0 removes the signer list.
+    #[binary(id = "SignerQuorum", rtype = "UInt32")]
+    pub signer_quorum: u32,
+    /// Up to 32 signers; `None` with zero quorum.
+    #[binary(id = "SignerEntries", rtype = "STArray")]
+    pub signer_entries: Option<Vec<SignerEntry>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::signer_list_set::{SignerEntry, SignerListSetTransaction, TRANSACTION_TYPE_SIGNER_LIST_SET};
+    use crate::txs::test_utils::{address, assert_serialized, ISSUER};
+    use crate::types::Hash;
+
+    #[test]
+    fn test_serialize() {
+        let mut wallet_locator = [0; 32];
+        wallet_locator[31] = 1;
+        let tx = test_transaction!(SignerListSetTransaction {
+            transaction_type: TRANSACTION_TYPE_SIGNER_LIST_SET,
+            flags: None,
+            signer_quorum: 3,
+            signer_entries: Some(vec![
+                SignerEntry {
+                    account: address(ISSUER),
+                    signer_weight: 2,
+                    wallet_locator: Some(Hash(wallet_locator)),
+                },
+                SignerEntry {
+                    account: address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"),
+                    signer_weight: 1,
+                    wallet_locator: None,
+                },
+            ]),
+        });
+        // `SignerEntries` (F4), each `SignerEntry` (EB) with `SignerWeight`, `WalletLocator`, `Account`, then E1; F1
+        assert_serialized(&tx, "5354580012000C240000000120230000000368400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C\
+            F4EB1300025700000000000000000000000000000000000000000000000000000000000000018114\
+            4B4E9C06F24296074F7BC48F92A97916C6DC5EA9E1EB1300018114204288D2E47F8EF6C99BCC457966320D12409711E1F1");
+    }
+}
@@ -0,0 +1,76 @@
+use xrpl_async_macroses::BinarySerialize;
+use crate::objects::amount::{Amount, AnyAmount};
+use crate::txs::{Transaction, TransactionSerializer};
+use crate::types::Hash;
+use crate::hashes::{AccountPublicKey, Address};
+
+pub const TRANSACTION_TYPE_TRUST_SET: i16 = 20;
+
+transaction_flags! {
+    TrustSetFlags {
+        /// Authorize the other party to hold the tokens (with `RequireAuth`).
+        SETF_AUTH = 0x00010000,
+        SET_NO_RIPPLE = 0x00020000,
+        CLEAR_NO_RIPPLE = 0x00040000,
+        SET_FREEZE = 0x00100000,
+        CLEAR_FREEZE = 0x00200000,
+        /// Also forbid the holder to receive the tokens; requires `SET_FREEZE` or an existing freeze.
+        SET_DEEP_FREEZE = 0x00400000,
+        CLEAR_DEEP_FREEZE = 0x00800000,
+    }
+}
+
+/// Create or modify a trust line
+#[derive(BinarySerialize, Clone, Debug)]
+pub struct TrustSetTransaction {
+    #[binary(id = "Account", rtype = "AccountID")]
+    pub account: Address,
+    #[binary(id = "TransactionType", rtype = "UInt16")]
+    pub transaction_type: i16,
+    #[binary(id = "Fee", rtype = "Amount")]
+    pub fee: Option<AnyAmount>,
+    #[binary(id = "Sequence", rtype = "UInt32")]
+    pub sequence: Option<u32>,
+    #[binary(id = "AccountTxnID", rtype = "Hash256")]
+    pub account_txn_id: Option<Hash<32>>,
+    #[binary(id = "Flags", rtype = "UInt32")]
+    pub flags: TrustSetFlags,
+    #[binary(id = "LastLedgerSequence", rtype = "UInt32")]
+    pub last_ledger_sequence: Option<u32>,
+    #[binary(id = "SourceTag", rtype = "UInt32")]
+    pub source_tag: Option<u32>,
+    #[binary(id = "SigningPubKey", rtype = "Blob")]
+    pub public_key: Option<AccountPublicKey>,
+    #[binary(id = "TicketSequence", rtype = "UInt32")]
+    pub ticket_sequence: Option<u32>,
+    #[binary(id = "TxnSignature", rtype = "Blob")]
+    pub signature: Option<Vec<u8>>,
+
+    /// `issuer` is the other party of the trust line; value 0 removes the limit.
+    #[binary(id = "LimitAmount", rtype = "Amount")]
+    pub limit_amount: Amount,
+    /// Value incoming balances at this ratio per 1000000000 units; 0 is face value.
+    #[binary(id = "QualityIn", rtype = "UInt32")]
+    pub quality_in: Option<u32>,
+    /// Value outgoing balances at this ratio per 1000000000 units; 0 is face value.
+    #[binary(id = "QualityOut", rtype = "UInt32")]
+    pub quality_out: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::txs::test_utils::{assert_serialized, usd};
+    use crate::txs::trust_set::{TrustSetFlags, TrustSetTransaction, TRANSACTION_TYPE_TRUST_SET};
+
+    #[test]
+    fn test_serialize() {
+        let tx = test_transaction!(TrustSetTransaction {
+            transaction_type: TRANSACTION_TYPE_TRUST_SET,
+            flags: TrustSetFlags::SET_NO_RIPPLE | TrustSetFlags::SET_FREEZE,
+            limit_amount: usd(100.0),
+            quality_in: None,
+            quality_out: None,
+        });
+        assert_serialized(&tx, "535458001200142200120000240000000163D5038D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000C81147CCFE86388B264396710C29F69025DB1DFA4AE4C");
+    }
+}
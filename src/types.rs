@@ -256,4 +256,129 @@ impl<'de> Deserialize<'de> for LedgerForResponse {
             current: value.ledger_current_index.is_some(),
         })
     }
-}
\ No newline at end of file
+}
+
+/// `UInt64` fields are represented in JSON as hex strings.
+pub mod uint64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use super::*;
+
+    pub fn serialize<S>(x: &u64, s: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        s.serialize_str(&format!("{:016X}", x))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+        where D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)
+            .and_then(|string| u64::from_str_radix(&string, 16).map_err(de::Error::custom))
+    }
+}
+
+pub mod option_uint64 {
+    use super::*;
+
+    struct Wrap(u64);
+
+    impl<'de> Deserialize<'de> for Wrap {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(Wrap(uint64::deserialize(deserializer)?))
+        }
+    }
+
+    pub fn serialize<S: Serializer>(x: &Option<u64>, s: S) -> Result<S::Ok, S::Error>
+    {
+        if let Some(x) = x {
+            uint64::serialize(x, s)
+        } else {
+            None::<()>.serialize(s)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error>
+    {
+        let result = Option::<Wrap>::deserialize(deserializer)?;
+        Ok(result.map(|v| v.0))
+    }
+}
+
+/// API v1 returns some numbers as strings, v2 as numbers.
+pub(crate) fn u64_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => n.as_u64().ok_or_else(|| de::Error::custom("expected unsigned integer")),
+        serde_json::Value::String(s) => s.parse::<u64>().map_err(de::Error::custom),
+        _ => Err(de::Error::custom("expected integer")),
+    }
+}
+
+/// `Blob` fields (such as `Domain` or `URI`) are represented in JSON as hex strings.
+pub mod blob {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use super::*;
+
+    pub fn serialize<S>(x: &[u8], s: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        s.serialize_str(&hex::encode_upper(x))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+        where D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)
+            .and_then(|string| decode(string).map_err(de::Error::custom))
+    }
+}
+
+pub mod option_blob {
+    use super::*;
+
+    struct Wrap(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for Wrap {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(Wrap(blob::deserialize(deserializer)?))
+        }
+    }
+
+    pub fn serialize<S: Serializer>(x: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error>
+    {
+        if let Some(x) = x {
+            blob::serialize(x, s)
+        } else {
+            None::<()>.serialize(s)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+    {
+        let result = Option::<Wrap>::deserialize(deserializer)?;
+        Ok(result.map(|v| v.0))
+    }
+}
+
+/// Elements of arrays of objects (such as `NFTokens` or `SignerEntries`) are represented in JSON
+/// wrapped by their field name: `[{"NFToken": {...}}, ...]`. For `deserialize_with` of a `Vec`.
+pub mod wrapped {
+    use std::collections::HashMap;
+    use serde::{Deserialize, Deserializer};
+    use super::*;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+        where D: Deserializer<'de>,
+              T: Deserialize<'de>,
+    {
+        Vec::<HashMap<String, T>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|wrapper| {
+                let mut fields = wrapper.into_values();
+                match (fields.next(), fields.next()) {
+                    (Some(element), None) => Ok(element),
+                    _ => Err(de::Error::custom("expected an array element wrapped in a single field")),
+                }
+            })
+            .collect()
+    }
+}